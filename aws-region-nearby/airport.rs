@@ -0,0 +1,511 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::aws::{find_region_from_list, AwsRegion};
+use crate::country::Country;
+use crate::latlon::{IntoLatLon, LatLon};
+use crate::Error;
+
+/// An airport, identified by its IATA code.
+///
+/// Edge networks name their POPs after the nearest airport, e.g. Cloudflare
+/// colos (`FRA`), CloudFront edge locations (`FRA56-C1`) and Fly.io regions
+/// (`fra`).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Airport {
+    code: &'static str,
+    city: &'static str,
+    country: &'static str,
+    latitude: f64,
+    longitude: f64,
+}
+
+impl Airport {
+    /// Returns an iterator over all airports.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        AIRPORTS.iter().copied()
+    }
+
+    /// Parses the airport from a POP identifier starting with an IATA code,
+    /// e.g. `FRA56-C1` or `fra`.
+    pub fn from_pop(pop: &str) -> Result<Self, Error> {
+        let code = pop.get(..3).ok_or(Error::InvalidAirport)?;
+        if pop[3..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return Err(Error::InvalidAirport);
+        }
+        code.parse()
+    }
+
+    /// Returns the IATA code of the airport, e.g. `FRA`.
+    pub const fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Returns the name of the city served by the airport.
+    pub const fn city(&self) -> &'static str {
+        self.city
+    }
+
+    /// Returns the country of the airport.
+    pub fn country(&self) -> Country {
+        self.country.parse().expect("airport in a known country")
+    }
+
+    /// Returns the location of the airport.
+    pub const fn location(&self) -> LatLon {
+        LatLon::new(self.latitude, self.longitude)
+    }
+
+    /// Finds the nearest AWS region from a list of regions to the airport.
+    ///
+    /// # Panics
+    ///
+    /// Panics if regions is empty.
+    pub fn find_region_from_list(&self, regions: &[AwsRegion]) -> AwsRegion {
+        find_region_from_list(self.location(), regions)
+    }
+}
+
+impl IntoLatLon for Airport {
+    fn into_lat_lon(self) -> LatLon {
+        self.location()
+    }
+}
+
+impl fmt::Display for Airport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code)
+    }
+}
+
+impl FromStr for Airport {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        AIRPORTS
+            .iter()
+            .find(|airport| airport.code.eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or(Error::InvalidAirport)
+    }
+}
+
+impl TryFrom<&str> for Airport {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Error> {
+        s.parse()
+    }
+}
+
+/// Finds the nearest AWS region from a list of regions to an edge POP, given
+/// its identifier, e.g. `FRA56-C1` from the `x-amz-cf-pop` header.
+///
+/// # Panics
+///
+/// Panics if regions is empty.
+pub fn find_region_for_pop(pop: &str, regions: &[AwsRegion]) -> Result<AwsRegion, Error> {
+    let airport = Airport::from_pop(pop)?;
+    Ok(airport.find_region_from_list(regions))
+}
+
+const fn airport(
+    code: &'static str,
+    city: &'static str,
+    country: &'static str,
+    latitude: f64,
+    longitude: f64,
+) -> Airport {
+    Airport {
+        code,
+        city,
+        country,
+        latitude,
+        longitude,
+    }
+}
+
+/// Airports with an edge POP of a major CDN or edge platform. Includes a few
+/// codes that are not airports but are used by edge networks, e.g. `YTO`
+/// (Toronto metropolitan area) and `ZDM` (Ramallah).
+static AIRPORTS: [Airport; 257] = [
+    airport("ABJ", "Abidjan", "CI", 5.2614, -3.9263),
+    airport("ACC", "Accra", "GH", 5.6052, -0.1668),
+    airport("ADD", "Addis Ababa", "ET", 8.9779, 38.7993),
+    airport("ADL", "Adelaide", "AU", -34.9450, 138.5306),
+    airport("AKL", "Auckland", "NZ", -37.0082, 174.7850),
+    airport("ALA", "Almaty", "KZ", 43.3521, 77.0405),
+    airport("ALG", "Algiers", "DZ", 36.6910, 3.2154),
+    airport("AMM", "Amman", "JO", 31.7226, 35.9932),
+    airport("AMS", "Amsterdam", "NL", 52.3105, 4.7683),
+    airport("ANC", "Anchorage", "US", 61.1743, -149.9962),
+    airport("ARI", "Arica", "CL", -18.3485, -70.3387),
+    airport("ARN", "Stockholm", "SE", 59.6498, 17.9238),
+    airport("ASU", "Asunción", "PY", -25.2400, -57.5191),
+    airport("ATH", "Athens", "GR", 37.9364, 23.9445),
+    airport("ATL", "Atlanta", "US", 33.6407, -84.4277),
+    airport("AUS", "Austin", "US", 30.1975, -97.6664),
+    airport("BAH", "Manama", "BH", 26.2708, 50.6336),
+    airport("BAQ", "Barranquilla", "CO", 10.8896, -74.7808),
+    airport("BBI", "Bhubaneswar", "IN", 20.2444, 85.8178),
+    airport("BCN", "Barcelona", "ES", 41.2974, 2.0833),
+    airport("BEG", "Belgrade", "RS", 44.8184, 20.3091),
+    airport("BEL", "Belém", "BR", -1.3792, -48.4763),
+    airport("BER", "Berlin", "DE", 52.3667, 13.5033),
+    airport("BEY", "Beirut", "LB", 33.8209, 35.4884),
+    airport("BGW", "Baghdad", "IQ", 33.2625, 44.2346),
+    airport("BKK", "Bangkok", "TH", 13.6900, 100.7501),
+    airport("BLR", "Bangalore", "IN", 13.1986, 77.7066),
+    airport("BNA", "Nashville", "US", 36.1263, -86.6774),
+    airport("BNE", "Brisbane", "AU", -27.3842, 153.1175),
+    airport("BOG", "Bogotá", "CO", 4.7016, -74.1469),
+    airport("BOM", "Mumbai", "IN", 19.0896, 72.8656),
+    airport("BOS", "Boston", "US", 42.3656, -71.0096),
+    airport("BRU", "Brussels", "BE", 50.9014, 4.4844),
+    airport("BSB", "Brasília", "BR", -15.8697, -47.9208),
+    airport("BTS", "Bratislava", "SK", 48.1702, 17.2127),
+    airport("BUD", "Budapest", "HU", 47.4298, 19.2611),
+    airport("BUF", "Buffalo", "US", 42.9405, -78.7322),
+    airport("BWI", "Baltimore", "US", 39.1774, -76.6684),
+    airport("CAI", "Cairo", "EG", 30.1219, 31.4056),
+    airport("CAN", "Guangzhou", "CN", 23.3924, 113.2988),
+    airport("CBR", "Canberra", "AU", -35.3069, 149.1950),
+    airport("CCU", "Kolkata", "IN", 22.6547, 88.4467),
+    airport("CDG", "Paris", "FR", 49.0097, 2.5479),
+    airport("CEB", "Cebu", "PH", 10.3075, 123.9794),
+    airport("CGK", "Jakarta", "ID", -6.1256, 106.6558),
+    airport("CGO", "Zhengzhou", "CN", 34.5197, 113.8408),
+    airport("CGP", "Chittagong", "BD", 22.2496, 91.8133),
+    airport("CHC", "Christchurch", "NZ", -43.4894, 172.5322),
+    airport("CLE", "Cleveland", "US", 41.4117, -81.8498),
+    airport("CLT", "Charlotte", "US", 35.2140, -80.9431),
+    airport("CMB", "Colombo", "LK", 7.1808, 79.8841),
+    airport("CMH", "Columbus", "US", 39.9980, -82.8919),
+    airport("CMN", "Casablanca", "MA", 33.3675, -7.5898),
+    airport("CNF", "Belo Horizonte", "BR", -19.6244, -43.9719),
+    airport("CNX", "Chiang Mai", "TH", 18.7668, 98.9626),
+    airport("COK", "Kochi", "IN", 10.1520, 76.4019),
+    airport("CPH", "Copenhagen", "DK", 55.6180, 12.6508),
+    airport("CPT", "Cape Town", "ZA", -33.9648, 18.6017),
+    airport("CRK", "Angeles City", "PH", 15.1860, 120.5603),
+    airport("CTU", "Chengdu", "CN", 30.5785, 103.9471),
+    airport("CUR", "Willemstad", "CW", 12.1889, -68.9598),
+    airport("CWB", "Curitiba", "BR", -25.5285, -49.1758),
+    airport("DAC", "Dhaka", "BD", 23.8433, 90.3978),
+    airport("DAD", "Da Nang", "VN", 16.0439, 108.1994),
+    airport("DAR", "Dar es Salaam", "TZ", -6.8781, 39.2026),
+    airport("DEL", "New Delhi", "IN", 28.5562, 77.1000),
+    airport("DEN", "Denver", "US", 39.8561, -104.6737),
+    airport("DFW", "Dallas", "US", 32.8998, -97.0403),
+    airport("DKR", "Dakar", "SN", 14.7397, -17.4902),
+    airport("DME", "Moscow", "RU", 55.4088, 37.9063),
+    airport("DMM", "Dammam", "SA", 26.4712, 49.7979),
+    airport("DOH", "Doha", "QA", 25.2731, 51.6081),
+    airport("DTW", "Detroit", "US", 42.2162, -83.3554),
+    airport("DUB", "Dublin", "IE", 53.4213, -6.2701),
+    airport("DUR", "Durban", "ZA", -29.6144, 31.1197),
+    airport("DUS", "Düsseldorf", "DE", 51.2895, 6.7668),
+    airport("DXB", "Dubai", "AE", 25.2532, 55.3657),
+    airport("EBB", "Kampala", "UG", 0.0424, 32.4435),
+    airport("EDI", "Edinburgh", "GB", 55.9500, -3.3725),
+    airport("EVN", "Yerevan", "AM", 40.1473, 44.3959),
+    airport("EWR", "Newark", "US", 40.6895, -74.1745),
+    airport("EZE", "Buenos Aires", "AR", -34.8222, -58.5358),
+    airport("FCO", "Rome", "IT", 41.8003, 12.2389),
+    airport("FIH", "Kinshasa", "CD", -4.3858, 15.4446),
+    airport("FLN", "Florianópolis", "BR", -27.6703, -48.5525),
+    airport("FOR", "Fortaleza", "BR", -3.7763, -38.5326),
+    airport("FRA", "Frankfurt", "DE", 50.0379, 8.5622),
+    airport("FSD", "Sioux Falls", "US", 43.5820, -96.7419),
+    airport("FUK", "Fukuoka", "JP", 33.5859, 130.4506),
+    airport("GDL", "Guadalajara", "MX", 20.5218, -103.3112),
+    airport("GEO", "Georgetown", "GY", 6.4985, -58.2541),
+    airport("GIG", "Rio de Janeiro", "BR", -22.8100, -43.2506),
+    airport("GND", "St. George's", "GD", 12.0042, -61.7862),
+    airport("GOT", "Gothenburg", "SE", 57.6628, 12.2798),
+    airport("GRU", "São Paulo", "BR", -23.4356, -46.4731),
+    airport("GUA", "Guatemala City", "GT", 14.5833, -90.5275),
+    airport("GUM", "Hagåtña", "GU", 13.4834, 144.7960),
+    airport("GVA", "Geneva", "CH", 46.2381, 6.1090),
+    airport("GYD", "Baku", "AZ", 40.4675, 50.0467),
+    airport("GYE", "Guayaquil", "EC", -2.1574, -79.8837),
+    airport("HAM", "Hamburg", "DE", 53.6304, 9.9882),
+    airport("HAN", "Hanoi", "VN", 21.2212, 105.8072),
+    airport("HAV", "Havana", "CU", 22.9892, -82.4091),
+    airport("HEL", "Helsinki", "FI", 60.3172, 24.9633),
+    airport("HIO", "Hillsboro", "US", 45.5404, -122.9498),
+    airport("HKG", "Hong Kong", "HK", 22.3080, 113.9185),
+    airport("HNL", "Honolulu", "US", 21.3187, -157.9225),
+    airport("HRE", "Harare", "ZW", -17.9318, 31.0928),
+    airport("HYD", "Hyderabad", "IN", 17.2403, 78.4294),
+    airport("IAD", "Ashburn", "US", 38.9531, -77.4565),
+    airport("IAH", "Houston", "US", 29.9902, -95.3368),
+    airport("ICN", "Seoul", "KR", 37.4602, 126.4407),
+    airport("IND", "Indianapolis", "US", 39.7173, -86.2944),
+    airport("ISB", "Islamabad", "PK", 33.5491, 72.8256),
+    airport("IST", "Istanbul", "TR", 41.2753, 28.7519),
+    airport("JAX", "Jacksonville", "US", 30.4941, -81.6879),
+    airport("JED", "Jeddah", "SA", 21.6796, 39.1565),
+    airport("JFK", "New York", "US", 40.6413, -73.7781),
+    airport("JHB", "Johor Bahru", "MY", 1.6413, 103.6697),
+    airport("JIB", "Djibouti", "DJ", 11.5473, 43.1595),
+    airport("JNB", "Johannesburg", "ZA", -26.1367, 28.2411),
+    airport("KBP", "Kyiv", "UA", 50.3450, 30.8947),
+    airport("KEF", "Reykjavík", "IS", 63.9850, -22.6056),
+    airport("KGL", "Kigali", "RW", -1.9686, 30.1395),
+    airport("KHH", "Kaohsiung", "TW", 22.5771, 120.3500),
+    airport("KHI", "Karachi", "PK", 24.9065, 67.1608),
+    airport("KIN", "Kingston", "JM", 17.9357, -76.7875),
+    airport("KIX", "Osaka", "JP", 34.4273, 135.2440),
+    airport("KJA", "Krasnoyarsk", "RU", 56.1729, 92.4933),
+    airport("KTM", "Kathmandu", "NP", 27.6966, 85.3591),
+    airport("KUL", "Kuala Lumpur", "MY", 2.7456, 101.7072),
+    airport("KWI", "Kuwait City", "KW", 29.2266, 47.9689),
+    airport("LAD", "Luanda", "AO", -8.8584, 13.2312),
+    airport("LAS", "Las Vegas", "US", 36.0840, -115.1537),
+    airport("LAX", "Los Angeles", "US", 33.9416, -118.4085),
+    airport("LCA", "Larnaca", "CY", 34.8751, 33.6249),
+    airport("LED", "Saint Petersburg", "RU", 59.8003, 30.2625),
+    airport("LHE", "Lahore", "PK", 31.5216, 74.4036),
+    airport("LHR", "London", "GB", 51.4700, -0.4543),
+    airport("LIM", "Lima", "PE", -12.0219, -77.1143),
+    airport("LIS", "Lisbon", "PT", 38.7742, -9.1342),
+    airport("LLA", "Luleå", "SE", 65.5438, 22.1220),
+    airport("LOS", "Lagos", "NG", 6.5774, 3.3212),
+    airport("LUX", "Luxembourg", "LU", 49.6233, 6.2044),
+    airport("LYS", "Lyon", "FR", 45.7256, 5.0811),
+    airport("MAA", "Chennai", "IN", 12.9941, 80.1709),
+    airport("MAD", "Madrid", "ES", 40.4983, -3.5676),
+    airport("MAN", "Manchester", "GB", 53.3588, -2.2727),
+    airport("MBA", "Mombasa", "KE", -4.0348, 39.5942),
+    airport("MCI", "Kansas City", "US", 39.2976, -94.7139),
+    airport("MCT", "Muscat", "OM", 23.5933, 58.2844),
+    airport("MDE", "Medellín", "CO", 6.1645, -75.4231),
+    airport("MEL", "Melbourne", "AU", -37.6690, 144.8410),
+    airport("MEM", "Memphis", "US", 35.0421, -89.9792),
+    airport("MEX", "Mexico City", "MX", 19.4361, -99.0719),
+    airport("MFM", "Macau", "MO", 22.1496, 113.5920),
+    airport("MIA", "Miami", "US", 25.7959, -80.2870),
+    airport("MLE", "Malé", "MV", 4.1918, 73.5291),
+    airport("MNL", "Manila", "PH", 14.5086, 121.0194),
+    airport("MPM", "Maputo", "MZ", -25.9208, 32.5726),
+    airport("MRS", "Marseille", "FR", 43.4393, 5.2214),
+    airport("MRU", "Port Louis", "MU", -20.4302, 57.6836),
+    airport("MSP", "Minneapolis", "US", 44.8848, -93.2223),
+    airport("MUC", "Munich", "DE", 48.3538, 11.7861),
+    airport("MVD", "Montevideo", "UY", -34.8384, -56.0308),
+    airport("MXP", "Milan", "IT", 45.6306, 8.7281),
+    airport("NAG", "Nagpur", "IN", 21.0922, 79.0472),
+    airport("NBO", "Nairobi", "KE", -1.3192, 36.9278),
+    airport("NOU", "Nouméa", "NC", -22.0146, 166.2129),
+    airport("NRT", "Tokyo", "JP", 35.7647, 140.3864),
+    airport("OKA", "Naha", "JP", 26.1958, 127.6459),
+    airport("OMA", "Omaha", "US", 41.3032, -95.8941),
+    airport("ORD", "Chicago", "US", 41.9742, -87.9073),
+    airport("ORF", "Norfolk", "US", 36.8946, -76.2012),
+    airport("OSL", "Oslo", "NO", 60.1976, 11.1004),
+    airport("OTP", "Bucharest", "RO", 44.5711, 26.0850),
+    airport("PAP", "Port-au-Prince", "HT", 18.5800, -72.2925),
+    airport("PAT", "Patna", "IN", 25.5913, 85.0880),
+    airport("PBH", "Paro", "BT", 27.4032, 89.4246),
+    airport("PBM", "Paramaribo", "SR", 5.4528, -55.1878),
+    airport("PDX", "Portland", "US", 45.5898, -122.5951),
+    airport("PER", "Perth", "AU", -31.9385, 115.9672),
+    airport("PHL", "Philadelphia", "US", 39.8744, -75.2424),
+    airport("PHX", "Phoenix", "US", 33.4352, -112.0101),
+    airport("PIT", "Pittsburgh", "US", 40.4915, -80.2329),
+    airport("PMO", "Palermo", "IT", 38.1760, 13.0910),
+    airport("PNH", "Phnom Penh", "KH", 11.5466, 104.8441),
+    airport("POA", "Porto Alegre", "BR", -29.9939, -51.1711),
+    airport("POS", "Port of Spain", "TT", 10.5954, -61.3372),
+    airport("PPT", "Papeete", "PF", -17.5537, -149.6073),
+    airport("PRG", "Prague", "CZ", 50.1008, 14.2600),
+    airport("PTY", "Panama City", "PA", 9.0714, -79.3835),
+    airport("PVG", "Shanghai", "CN", 31.1443, 121.8083),
+    airport("QRO", "Querétaro", "MX", 20.6173, -100.1857),
+    airport("QWJ", "Americana", "BR", -22.7549, -47.2701),
+    airport("RAO", "Ribeirão Preto", "BR", -21.1364, -47.7767),
+    airport("RDU", "Raleigh", "US", 35.8801, -78.7880),
+    airport("REC", "Recife", "BR", -8.1265, -34.9236),
+    airport("RGN", "Yangon", "MM", 16.9073, 96.1332),
+    airport("RIC", "Richmond", "US", 37.5052, -77.3197),
+    airport("RIX", "Riga", "LV", 56.9236, 23.9711),
+    airport("RUH", "Riyadh", "SA", 24.9576, 46.6988),
+    airport("RUN", "Saint-Denis", "RE", -20.8871, 55.5103),
+    airport("SAN", "San Diego", "US", 32.7338, -117.1933),
+    airport("SAT", "San Antonio", "US", 29.5337, -98.4698),
+    airport("SCL", "Santiago", "CL", -33.3930, -70.7858),
+    airport("SDQ", "Santo Domingo", "DO", 18.4297, -69.6689),
+    airport("SEA", "Seattle", "US", 47.4502, -122.3088),
+    airport("SFO", "San Francisco", "US", 37.6190, -122.3750),
+    airport("SGN", "Ho Chi Minh City", "VN", 10.8188, 106.6520),
+    airport("SIN", "Singapore", "SG", 1.3644, 103.9915),
+    airport("SJC", "San Jose", "US", 37.3639, -121.9289),
+    airport("SJO", "San José", "CR", 9.9939, -84.2088),
+    airport("SJU", "San Juan", "PR", 18.4394, -66.0018),
+    airport("SKG", "Thessaloniki", "GR", 40.5197, 22.9709),
+    airport("SKP", "Skopje", "MK", 41.9616, 21.6214),
+    airport("SLC", "Salt Lake City", "US", 40.7899, -111.9791),
+    airport("SMF", "Sacramento", "US", 38.6954, -121.5908),
+    airport("SOF", "Sofia", "BG", 42.6967, 23.4114),
+    airport("SSA", "Salvador", "BR", -12.9086, -38.3225),
+    airport("STL", "St. Louis", "US", 38.7487, -90.3700),
+    airport("STR", "Stuttgart", "DE", 48.6899, 9.1920),
+    airport("SYD", "Sydney", "AU", -33.9461, 151.1772),
+    airport("SZX", "Shenzhen", "CN", 22.6393, 113.8107),
+    airport("TAS", "Tashkent", "UZ", 41.2579, 69.2812),
+    airport("TBS", "Tbilisi", "GE", 41.6692, 44.9547),
+    airport("TGU", "Tegucigalpa", "HN", 14.0608, -87.2172),
+    airport("TIA", "Tirana", "AL", 41.4147, 19.7206),
+    airport("TLH", "Tallahassee", "US", 30.3965, -84.3503),
+    airport("TLL", "Tallinn", "EE", 59.4133, 24.8328),
+    airport("TLV", "Tel Aviv", "IL", 32.0055, 34.8854),
+    airport("TNR", "Antananarivo", "MG", -18.7969, 47.4788),
+    airport("TPA", "Tampa", "US", 27.9755, -82.5332),
+    airport("TPE", "Taipei", "TW", 25.0797, 121.2342),
+    airport("TUN", "Tunis", "TN", 36.8510, 10.2272),
+    airport("TXL", "Berlin", "DE", 52.5597, 13.2877),
+    airport("UIO", "Quito", "EC", -0.1292, -78.3575),
+    airport("ULN", "Ulaanbaatar", "MN", 47.6433, 106.8195),
+    airport("VIE", "Vienna", "AT", 48.1103, 16.5697),
+    airport("VNO", "Vilnius", "LT", 54.6341, 25.2858),
+    airport("VTE", "Vientiane", "LA", 17.9883, 102.5633),
+    airport("WAW", "Warsaw", "PL", 52.1657, 20.9671),
+    airport("WDH", "Windhoek", "NA", -22.4799, 17.4709),
+    airport("WLG", "Wellington", "NZ", -41.3272, 174.8053),
+    airport("XNH", "Nasiriyah", "IQ", 30.9358, 46.0901),
+    airport("YHZ", "Halifax", "CA", 44.8808, -63.5086),
+    airport("YOW", "Ottawa", "CA", 45.3225, -75.6692),
+    airport("YTO", "Toronto", "CA", 43.6532, -79.3832),
+    airport("YUL", "Montreal", "CA", 45.4706, -73.7408),
+    airport("YVR", "Vancouver", "CA", 49.1967, -123.1815),
+    airport("YWG", "Winnipeg", "CA", 49.9100, -97.2399),
+    airport("YXE", "Saskatoon", "CA", 52.1708, -106.6997),
+    airport("YYC", "Calgary", "CA", 51.1215, -114.0076),
+    airport("YYZ", "Toronto", "CA", 43.6777, -79.6248),
+    airport("ZAG", "Zagreb", "HR", 45.7429, 16.0688),
+    airport("ZDM", "Ramallah", "PS", 31.9026, 35.2061),
+    airport("ZRH", "Zurich", "CH", 47.4582, 8.5555),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aws::find_region;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_airport_from_str() {
+        let airport: Airport = "fra".parse().unwrap();
+        assert_eq!(airport.code(), "FRA");
+        assert_eq!(airport.city(), "Frankfurt");
+        assert_eq!(airport.country().code(), "DE");
+        assert_eq!(airport.to_string(), "FRA");
+
+        assert_eq!(Airport::try_from("NRT").unwrap().city(), "Tokyo");
+        assert_eq!("XYZ".parse::<Airport>(), Err(Error::InvalidAirport));
+        assert_eq!("FRA56".parse::<Airport>(), Err(Error::InvalidAirport));
+    }
+
+    #[test]
+    fn test_airport_from_pop() {
+        struct Test {
+            pop: &'static str,
+            airport: Result<&'static str, Error>,
+        }
+
+        let tests = vec![
+            Test {
+                pop: "FRA56-C1",
+                airport: Ok("FRA"),
+            },
+            Test {
+                pop: "IAD89-P3",
+                airport: Ok("IAD"),
+            },
+            Test {
+                pop: "hio50-c1",
+                airport: Ok("HIO"),
+            },
+            Test {
+                pop: "sin",
+                airport: Ok("SIN"),
+            },
+            Test {
+                pop: "FRAN1",
+                airport: Err(Error::InvalidAirport),
+            },
+            Test {
+                pop: "XYZ1-C1",
+                airport: Err(Error::InvalidAirport),
+            },
+            Test {
+                pop: "FR",
+                airport: Err(Error::InvalidAirport),
+            },
+            Test {
+                pop: "Zürich",
+                airport: Err(Error::InvalidAirport),
+            },
+        ];
+
+        for t in tests {
+            let airport = Airport::from_pop(t.pop).map(|airport| airport.code());
+            assert_eq!(airport, t.airport, "{}", t.pop);
+        }
+    }
+
+    #[test]
+    fn test_airport_into_lat_lon() {
+        let airport: Airport = "DUB".parse().unwrap();
+        assert_eq!(airport.into_lat_lon(), airport.location());
+        assert_eq!(find_region(airport), AwsRegion::EuWest1);
+    }
+
+    #[test]
+    fn test_airport_iter() {
+        // Sorted and unique
+        let codes: Vec<&str> = Airport::iter().map(|airport| airport.code()).collect();
+        assert!(codes.windows(2).all(|w| w[0] < w[1]));
+
+        assert!(Airport::iter().all(|airport| airport.code().len() == 3));
+        assert!(Airport::iter().all(|airport| airport.country().code() == airport.country));
+    }
+
+    #[test]
+    fn test_find_region_for_pop() {
+        struct Test {
+            pop: &'static str,
+            list: Vec<AwsRegion>,
+            region: AwsRegion,
+        }
+
+        let tests = vec![
+            Test {
+                pop: "FRA56-C1",
+                list: vec![AwsRegion::EuWest1, AwsRegion::EuCentral1, AwsRegion::UsEast1],
+                region: AwsRegion::EuCentral1,
+            },
+            Test {
+                pop: "SEA19-C2",
+                list: vec![AwsRegion::UsEast1, AwsRegion::UsWest1, AwsRegion::UsWest2],
+                region: AwsRegion::UsWest2,
+            },
+            Test {
+                pop: "KIX",
+                list: vec![AwsRegion::ApNortheast1, AwsRegion::ApNortheast3],
+                region: AwsRegion::ApNortheast3,
+            },
+        ];
+
+        for t in tests {
+            let region = find_region_for_pop(t.pop, &t.list);
+            assert_eq!(region, Ok(t.region), "{}", t.pop);
+        }
+
+        assert_eq!(
+            find_region_for_pop("XYZ1", &[AwsRegion::EuWest1]),
+            Err(Error::InvalidAirport)
+        );
+    }
+}
@@ -0,0 +1,303 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::aws::AwsRegion;
+use crate::latlon::{IntoLatLon, LatLon};
+use crate::Error;
+
+impl AwsRegion {
+    /// Returns the short code of the region used in AZ IDs, e.g. `use1` for
+    /// us-east-1.
+    pub const fn short_code(&self) -> &'static str {
+        match *self {
+            Self::AfSouth1 => "afs1",
+            Self::ApEast1 => "ape1",
+            Self::ApNortheast1 => "apne1",
+            Self::ApNortheast2 => "apne2",
+            Self::ApNortheast3 => "apne3",
+            Self::ApSouth1 => "aps1",
+            Self::ApSoutheast1 => "apse1",
+            Self::ApSoutheast2 => "apse2",
+            Self::ApSoutheast3 => "apse3",
+            Self::CaCentral1 => "cac1",
+            Self::CnNorth1 => "cnn1",
+            Self::CnNorthwest1 => "cnnw1",
+            Self::EuCentral1 => "euc1",
+            Self::EuNorth1 => "eun1",
+            Self::EuSouth1 => "eus1",
+            Self::EuWest1 => "euw1",
+            Self::EuWest2 => "euw2",
+            Self::EuWest3 => "euw3",
+            Self::MeCentral1 => "mec1",
+            Self::MeSouth1 => "mes1",
+            Self::SaEast1 => "sae1",
+            Self::UsEast1 => "use1",
+            Self::UsEast2 => "use2",
+            Self::UsWest1 => "usw1",
+            Self::UsWest2 => "usw2",
+            Self::UsGovEast1 => "usge1",
+            Self::UsGovWest1 => "usgw1",
+        }
+    }
+
+    /// Returns the region with the given short code, ignoring ASCII case.
+    pub const fn from_short_code(code: &str) -> Option<Self> {
+        let regions = Self::all();
+        let mut i = 0;
+        while i < regions.len() {
+            if regions[i].short_code().eq_ignore_ascii_case(code) {
+                return Some(regions[i]);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Returns an iterator over the Availability Zones of the region.
+    ///
+    /// ```
+    /// use aws_region_nearby::AwsRegion;
+    ///
+    /// let ids: Vec<String> = AwsRegion::UsWest1.availability_zones().map(|az| az.to_string()).collect();
+    /// assert_eq!(ids, ["usw1-az1", "usw1-az3"]);
+    /// ```
+    pub fn availability_zones(&self) -> impl Iterator<Item = AvailabilityZone> {
+        let region = *self;
+        region
+            .zone_numbers()
+            .iter()
+            .map(move |&number| AvailabilityZone { region, number })
+    }
+
+    const fn zone_numbers(&self) -> &'static [u8] {
+        let mut i = 0;
+        while i < AVAILABILITY_ZONES.len() {
+            if AVAILABILITY_ZONES[i].0 as u8 == *self as u8 {
+                return AVAILABILITY_ZONES[i].1;
+            }
+            i += 1;
+        }
+        &[]
+    }
+}
+
+/// An Availability Zone, identified by its AZ ID, e.g. `use1-az4`.
+///
+/// Unlike AZ names such as `us-east-1a`, which are mapped to different zones
+/// in each AWS account, AZ IDs refer to the same zone in all accounts.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AvailabilityZone {
+    region: AwsRegion,
+    number: u8,
+}
+
+impl AvailabilityZone {
+    /// Returns an iterator over the Availability Zones of all regions.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        AwsRegion::iter().flat_map(|region| region.availability_zones())
+    }
+
+    /// Returns the Availability Zone with the given AZ ID, ignoring ASCII
+    /// case.
+    pub fn from_id(id: &str) -> Option<Self> {
+        let (code, number) = id.rsplit_once('-')?;
+        if !number.get(..2)?.eq_ignore_ascii_case("az") {
+            return None;
+        }
+        // Only canonical numbers, so that the ID round-trips
+        let number = &number[2..];
+        if number.starts_with('0') || !number.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let number = number.parse().ok()?;
+        let region = AwsRegion::from_short_code(code)?;
+
+        region
+            .zone_numbers()
+            .contains(&number)
+            .then_some(Self { region, number })
+    }
+
+    /// Returns the AZ ID, e.g. `use1-az4`.
+    #[cfg(feature = "std")]
+    pub fn id(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns the region of the Availability Zone.
+    pub const fn region(&self) -> AwsRegion {
+        self.region
+    }
+
+    /// Returns the number of the Availability Zone within its region, e.g. 4
+    /// for `use1-az4`.
+    pub const fn number(&self) -> u8 {
+        self.number
+    }
+
+    /// Returns the location of the region of the Availability Zone, as the
+    /// locations of individual zones are not published.
+    pub const fn location(&self) -> LatLon {
+        self.region.location()
+    }
+}
+
+impl IntoLatLon for AvailabilityZone {
+    fn into_lat_lon(self) -> LatLon {
+        self.location()
+    }
+}
+
+impl fmt::Display for AvailabilityZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-az{}", self.region.short_code(), self.number)
+    }
+}
+
+impl FromStr for AvailabilityZone {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Self::from_id(s).ok_or(Error::InvalidAvailabilityZone)
+    }
+}
+
+impl TryFrom<&str> for AvailabilityZone {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Error> {
+        s.parse()
+    }
+}
+
+/// The AZ ID numbers of each region. Numbers can have gaps where a zone was
+/// retired or is not available to new accounts.
+// Taken from https://docs.aws.amazon.com/global-infrastructure/latest/regions/aws-availability-zones.html
+static AVAILABILITY_ZONES: [(AwsRegion, &[u8]); 27] = [
+    (AwsRegion::AfSouth1, &[1, 2, 3]),
+    (AwsRegion::ApEast1, &[1, 2, 3]),
+    (AwsRegion::ApNortheast1, &[1, 2, 4]),
+    (AwsRegion::ApNortheast2, &[1, 2, 3, 4]),
+    (AwsRegion::ApNortheast3, &[1, 2, 3]),
+    (AwsRegion::ApSouth1, &[1, 2, 3]),
+    (AwsRegion::ApSoutheast1, &[1, 2, 3]),
+    (AwsRegion::ApSoutheast2, &[1, 2, 3]),
+    (AwsRegion::ApSoutheast3, &[1, 2, 3]),
+    (AwsRegion::CaCentral1, &[1, 2, 4]),
+    (AwsRegion::CnNorth1, &[1, 2, 4]),
+    (AwsRegion::CnNorthwest1, &[1, 2, 3]),
+    (AwsRegion::EuCentral1, &[1, 2, 3]),
+    (AwsRegion::EuNorth1, &[1, 2, 3]),
+    (AwsRegion::EuSouth1, &[1, 2, 3]),
+    (AwsRegion::EuWest1, &[1, 2, 3]),
+    (AwsRegion::EuWest2, &[1, 2, 3]),
+    (AwsRegion::EuWest3, &[1, 2, 3]),
+    (AwsRegion::MeCentral1, &[1, 2, 3]),
+    (AwsRegion::MeSouth1, &[1, 2, 3]),
+    (AwsRegion::SaEast1, &[1, 2, 3]),
+    (AwsRegion::UsEast1, &[1, 2, 3, 4, 5, 6]),
+    (AwsRegion::UsEast2, &[1, 2, 3]),
+    (AwsRegion::UsWest1, &[1, 3]),
+    (AwsRegion::UsWest2, &[1, 2, 3, 4]),
+    (AwsRegion::UsGovEast1, &[1, 2, 3]),
+    (AwsRegion::UsGovWest1, &[1, 2, 3]),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_short_code() {
+        assert!(AwsRegion::iter().all(|region| AwsRegion::from_short_code(region.short_code()) == Some(region)));
+        assert_eq!(AwsRegion::from_short_code("APNE1"), Some(AwsRegion::ApNortheast1));
+        assert_eq!(AwsRegion::from_short_code("usgw1"), Some(AwsRegion::UsGovWest1));
+        assert_eq!(AwsRegion::from_short_code("us-east-1"), None);
+    }
+
+    #[test]
+    fn test_availability_zones() {
+        assert!(AwsRegion::iter().all(|region| region.availability_zones().count() >= 2));
+        assert_eq!(AwsRegion::UsEast1.availability_zones().count(), 6);
+        assert_eq!(
+            AwsRegion::ApNortheast1
+                .availability_zones()
+                .map(|az| az.number())
+                .collect::<Vec<_>>(),
+            [1, 2, 4]
+        );
+        assert!(AvailabilityZone::iter().all(|az| az.to_string().parse() == Ok(az)));
+    }
+
+    #[test]
+    fn test_availability_zone_from_str() {
+        struct Test {
+            s: &'static str,
+            az: Result<(AwsRegion, u8), Error>,
+        }
+
+        let tests = vec![
+            Test {
+                s: "use1-az4",
+                az: Ok((AwsRegion::UsEast1, 4)),
+            },
+            Test {
+                s: "EUC1-AZ2",
+                az: Ok((AwsRegion::EuCentral1, 2)),
+            },
+            Test {
+                s: "cnn1-az4",
+                az: Ok((AwsRegion::CnNorth1, 4)),
+            },
+            Test {
+                s: "cnn1-az3",
+                az: Err(Error::InvalidAvailabilityZone),
+            },
+            Test {
+                s: "cnnw1-az3",
+                az: Ok((AwsRegion::CnNorthwest1, 3)),
+            },
+            Test {
+                s: "usw1-az2",
+                az: Err(Error::InvalidAvailabilityZone),
+            },
+            Test {
+                s: "use1-az7",
+                az: Err(Error::InvalidAvailabilityZone),
+            },
+            Test {
+                s: "us-east-1a",
+                az: Err(Error::InvalidAvailabilityZone),
+            },
+            Test {
+                s: "use1-az",
+                az: Err(Error::InvalidAvailabilityZone),
+            },
+            Test {
+                s: "use1-az+4",
+                az: Err(Error::InvalidAvailabilityZone),
+            },
+            Test {
+                s: "use1-az04",
+                az: Err(Error::InvalidAvailabilityZone),
+            },
+            Test {
+                s: "use1-4",
+                az: Err(Error::InvalidAvailabilityZone),
+            },
+        ];
+
+        for t in tests {
+            let az = t.s.parse::<AvailabilityZone>().map(|az| (az.region(), az.number()));
+            assert_eq!(az, t.az, "{}", t.s);
+        }
+    }
+
+    #[test]
+    fn test_availability_zone_location() {
+        let az = AvailabilityZone::try_from("apse2-az1").unwrap();
+        assert_eq!(az.location(), AwsRegion::ApSoutheast2.location());
+        assert_eq!(az.to_string(), "apse2-az1");
+    }
+}
@@ -1,8 +1,7 @@
-use std::fmt;
-use std::str::FromStr;
-
-use geoutils::Location;
+use core::fmt;
+use core::str::FromStr;
 
+use crate::latlon::{IntoLatLon, LatLon};
 use crate::Error;
 
 /// An AWS region.
@@ -127,6 +126,26 @@ impl AwsRegion {
         AWS_REGIONS.iter().copied()
     }
 
+    /// Returns all regions, for iterating in `const` functions.
+    pub(crate) const fn all() -> &'static [Self] {
+        &AWS_REGIONS
+    }
+
+    /// Returns the region with the given name, ignoring ASCII case.
+    ///
+    /// Unlike parsing, this can be evaluated at compile time. See also
+    /// [`aws_region!`](crate::aws_region).
+    pub const fn from_name(name: &str) -> Option<Self> {
+        let mut i = 0;
+        while i < AWS_REGIONS.len() {
+            if AWS_REGIONS[i].name().eq_ignore_ascii_case(name) {
+                return Some(AWS_REGIONS[i]);
+            }
+            i += 1;
+        }
+        None
+    }
+
     /// Returns the name of the region.
     pub const fn name(&self) -> &'static str {
         match *self {
@@ -163,39 +182,39 @@ impl AwsRegion {
     /// Returns the location of the region.
     // Coordinates taken from https://gist.github.com/tobilg/ba6a5e1635478d13efdea5c1cd8227de
     // TODO: share city coordinates with DenoRegion
-    pub const fn location(&self) -> Location {
+    pub const fn location(&self) -> LatLon {
         match *self {
-            Self::AfSouth1 => Location::new_const(-33.9648017883, 18.6016998291), // Cape Town, South Africa
-            Self::ApEast1 => Location::new_const(22.308901, 113.915001),          // Hong Kong, China
-            Self::ApNortheast1 => Location::new_const(35.764702, 140.386002),     // Tokyo, Japan
-            Self::ApNortheast2 => Location::new_const(37.46910095214844, 126.45099639892578), // Seoul, South Korea
-            Self::ApNortheast3 => Location::new_const(34.42729949951172, 135.24400329589844), // Osaka, Japan
-            Self::ApSouth1 => Location::new_const(19.0886993408, 72.8678970337),  // Mumbai, India
-            Self::ApSoutheast1 => Location::new_const(1.35019, 103.994003),       // Singapore
-            Self::ApSoutheast2 => Location::new_const(-33.94609832763672, 151.177001953125), // Syndney, Australia
-            Self::ApSoutheast3 => Location::new_const(-6.125556, 106.655833),     // Jakarta, Indonesia
-            Self::CaCentral1 => Location::new_const(45.470556, -73.740833),       // Montreal, Canada
-            Self::CnNorth1 => Location::new_const(40.080101013183594, 116.58499908447266), // Beijing, China
-            Self::CnNorthwest1 => Location::new_const(38.321667, 106.3925),       // Yinchuan, China
-            Self::EuCentral1 => Location::new_const(50.033333, 8.570556),         // Frankfurt, Germany
-            Self::EuNorth1 => Location::new_const(59.651901245117, 17.918600082397), // Stockholm, Sweden
-            Self::EuSouth1 => Location::new_const(45.6306, 8.72811),              // Milan, Italy
-            Self::EuWest1 => Location::new_const(53.421299, -6.27007),            // Dublin, Ireland
-            Self::EuWest2 => Location::new_const(51.4775, -0.461389),             // London, United Kingdom
-            Self::EuWest3 => Location::new_const(49.012798, 2.55),                // Paris, France
-            Self::MeCentral1 => Location::new_const(25.2697, 55.3094),            // Dubai, United Arab Emirates
-            Self::MeSouth1 => Location::new_const(26.27079963684082, 50.63359832763672), // Manama, Bahrain
-            Self::SaEast1 => Location::new_const(-23.435556, -46.473056),         // São Paulo, Brazil
-            Self::UsEast1 | Self::UsGovEast1 => Location::new_const(38.9445, -77.4558029), // Ashburn, Virginia, USA
-            Self::UsEast2 => Location::new_const(39.958993960575775, -83.00219086148725), // Columbus, Ohio, USA
-            Self::UsWest1 | Self::UsGovWest1 => Location::new_const(37.61899948120117, -122.375), // San Francisco, California, USA
-            Self::UsWest2 => Location::new_const(45.540394, -122.949825), // Hillsboro, Oregon, USA
+            Self::AfSouth1 => LatLon::new(-33.9648017883, 18.6016998291), // Cape Town, South Africa
+            Self::ApEast1 => LatLon::new(22.308901, 113.915001),          // Hong Kong, China
+            Self::ApNortheast1 => LatLon::new(35.764702, 140.386002),     // Tokyo, Japan
+            Self::ApNortheast2 => LatLon::new(37.46910095214844, 126.45099639892578), // Seoul, South Korea
+            Self::ApNortheast3 => LatLon::new(34.42729949951172, 135.24400329589844), // Osaka, Japan
+            Self::ApSouth1 => LatLon::new(19.0886993408, 72.8678970337),  // Mumbai, India
+            Self::ApSoutheast1 => LatLon::new(1.35019, 103.994003),       // Singapore
+            Self::ApSoutheast2 => LatLon::new(-33.94609832763672, 151.177001953125), // Syndney, Australia
+            Self::ApSoutheast3 => LatLon::new(-6.125556, 106.655833),     // Jakarta, Indonesia
+            Self::CaCentral1 => LatLon::new(45.470556, -73.740833),       // Montreal, Canada
+            Self::CnNorth1 => LatLon::new(40.080101013183594, 116.58499908447266), // Beijing, China
+            Self::CnNorthwest1 => LatLon::new(38.321667, 106.3925),       // Yinchuan, China
+            Self::EuCentral1 => LatLon::new(50.033333, 8.570556),         // Frankfurt, Germany
+            Self::EuNorth1 => LatLon::new(59.651901245117, 17.918600082397), // Stockholm, Sweden
+            Self::EuSouth1 => LatLon::new(45.6306, 8.72811),              // Milan, Italy
+            Self::EuWest1 => LatLon::new(53.421299, -6.27007),            // Dublin, Ireland
+            Self::EuWest2 => LatLon::new(51.4775, -0.461389),             // London, United Kingdom
+            Self::EuWest3 => LatLon::new(49.012798, 2.55),                // Paris, France
+            Self::MeCentral1 => LatLon::new(25.2697, 55.3094),            // Dubai, United Arab Emirates
+            Self::MeSouth1 => LatLon::new(26.27079963684082, 50.63359832763672), // Manama, Bahrain
+            Self::SaEast1 => LatLon::new(-23.435556, -46.473056),         // São Paulo, Brazil
+            Self::UsEast1 | Self::UsGovEast1 => LatLon::new(38.9445, -77.4558029), // Ashburn, Virginia, USA
+            Self::UsEast2 => LatLon::new(39.958993960575775, -83.00219086148725), // Columbus, Ohio, USA
+            Self::UsWest1 | Self::UsGovWest1 => LatLon::new(37.61899948120117, -122.375), // San Francisco, California, USA
+            Self::UsWest2 => LatLon::new(45.540394, -122.949825),                         // Hillsboro, Oregon, USA
         }
     }
 
     /// Returns the distance in meters between the region and the given location.
-    pub fn distance_to(&self, to: &Location) -> f64 {
-        self.location().haversine_distance_to(to).meters()
+    pub fn distance_to<L: IntoLatLon>(&self, to: L) -> f64 {
+        self.location().distance_to(to)
     }
 }
 
@@ -209,35 +228,7 @@ impl FromStr for AwsRegion {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Error> {
-        match s.to_lowercase().as_ref() {
-            "af-south-1" => Ok(Self::AfSouth1),
-            "ap-east-1" => Ok(Self::ApEast1),
-            "ap-northeast-1" => Ok(Self::ApNortheast1),
-            "ap-northeast-2" => Ok(Self::ApNortheast2),
-            "ap-northeast-3" => Ok(Self::ApNortheast3),
-            "ap-south-1" => Ok(Self::ApSouth1),
-            "ap-southeast-1" => Ok(Self::ApSoutheast1),
-            "ap-southeast-2" => Ok(Self::ApSoutheast2),
-            "ap-southeast-3" => Ok(Self::ApSoutheast3),
-            "ca-central-1" => Ok(Self::CaCentral1),
-            "cn-north-1" => Ok(Self::CnNorth1),
-            "cn-northwest-1" => Ok(Self::CnNorthwest1),
-            "eu-central-1" => Ok(Self::EuCentral1),
-            "eu-north-1" => Ok(Self::EuNorth1),
-            "eu-south-1" => Ok(Self::EuSouth1),
-            "eu-west-1" => Ok(Self::EuWest1),
-            "eu-west-2" => Ok(Self::EuWest2),
-            "eu-west-3" => Ok(Self::EuWest3),
-            "me-south-1" => Ok(Self::MeSouth1),
-            "sa-east-1" => Ok(Self::SaEast1),
-            "us-east-1" => Ok(Self::UsEast1),
-            "us-east-2" => Ok(Self::UsEast2),
-            "us-west-1" => Ok(Self::UsWest1),
-            "us-west-2" => Ok(Self::UsWest2),
-            "us-gov-east-1" => Ok(Self::UsGovEast1),
-            "us-gov-west-1" => Ok(Self::UsGovWest1),
-            _ => Err(Error::InvalidAwsRegion),
-        }
+        Self::from_name(s).ok_or(Error::InvalidAwsRegion)
     }
 }
 
@@ -249,9 +240,10 @@ impl TryFrom<&str> for AwsRegion {
     }
 }
 
-/// Finds the nearest AWS region to the given location.
-pub fn find_region<T: Into<f64>>(latitude: T, longitude: T) -> AwsRegion {
-    find_region_from_list(latitude, longitude, &AWS_REGIONS)
+/// Finds the nearest AWS region to the given location, e.g. a `(latitude,
+/// longitude)` tuple.
+pub fn find_region<L: IntoLatLon>(location: L) -> AwsRegion {
+    find_region_from_list(location, &AWS_REGIONS)
 }
 
 /// Finds the nearest AWS region from a list of regions.
@@ -259,15 +251,74 @@ pub fn find_region<T: Into<f64>>(latitude: T, longitude: T) -> AwsRegion {
 /// # Panics
 ///
 /// Panics if regions is empty.
-pub fn find_region_from_list<T: Into<f64>>(latitude: T, longitude: T, regions: &[AwsRegion]) -> AwsRegion {
-    let location = Location::new(latitude.into(), longitude.into());
+pub fn find_region_from_list<L: IntoLatLon>(location: L, regions: &[AwsRegion]) -> AwsRegion {
+    let location = location.into_lat_lon();
 
     *regions
         .iter()
-        .min_by_key(|region| ordered_float::OrderedFloat(region.distance_to(&location)))
+        .min_by_key(|region| ordered_float::OrderedFloat(region.distance_to(location)))
         .expect("regions must not be empty")
 }
 
+/// Finds the nearest AWS region to the given location at compile time, e.g.
+/// to build static routing tables.
+///
+/// This is the `const` counterpart of [`find_region`]. It may only disagree
+/// for locations less than a millimeter from being equidistant to two regions.
+pub const fn find_region_const(location: LatLon) -> AwsRegion {
+    find_region_from_list_const(location, &AWS_REGIONS)
+}
+
+/// Finds the nearest AWS region from a list of regions at compile time.
+///
+/// This is the `const` counterpart of [`find_region_from_list`].
+///
+/// # Panics
+///
+/// Panics if regions is empty.
+pub const fn find_region_from_list_const(location: LatLon, regions: &[AwsRegion]) -> AwsRegion {
+    assert!(!regions.is_empty(), "regions must not be empty");
+
+    let mut nearest = regions[0];
+    let mut nearest_haversine = nearest.location().const_haversine(location);
+    let mut i = 1;
+    while i < regions.len() {
+        let haversine = regions[i].location().const_haversine(location);
+        if haversine < nearest_haversine {
+            nearest = regions[i];
+            nearest_haversine = haversine;
+        }
+        i += 1;
+    }
+    nearest
+}
+
+/// Creates an [`AwsRegion`] from a region name literal, which is checked at
+/// compile time.
+///
+/// ```
+/// use aws_region_nearby::{aws_region, AwsRegion};
+///
+/// const HOME_REGION: AwsRegion = aws_region!("eu-west-1");
+/// assert_eq!(HOME_REGION, AwsRegion::EuWest1);
+/// ```
+///
+/// Invalid names don't compile:
+///
+/// ```compile_fail
+/// let region = aws_region_nearby::aws_region!("eu-west-9");
+/// ```
+#[macro_export]
+macro_rules! aws_region {
+    ($name:literal) => {{
+        const REGION: $crate::AwsRegion = match $crate::AwsRegion::from_name($name) {
+            ::core::option::Option::Some(region) => region,
+            ::core::option::Option::None => ::core::panic!(::core::concat!("invalid AWS region: ", $name)),
+        };
+        REGION
+    }};
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -290,6 +341,8 @@ mod tests {
         assert_eq!(AwsRegion::from_str("eu-central-1"), Ok(AwsRegion::EuCentral1));
         assert_eq!("EU-CENTRAL-1".parse(), Ok(AwsRegion::EuCentral1));
         assert_eq!("eu-central-1".try_into(), Ok(AwsRegion::EuCentral1));
+        assert_eq!("Me-Central-1".parse(), Ok(AwsRegion::MeCentral1));
+        assert!(AwsRegion::iter().all(|region| region.name().to_uppercase().parse() == Ok(region)));
 
         assert_eq!(AwsRegion::from_str("some-fake-region"), Err(Error::InvalidAwsRegion));
     }
@@ -297,7 +350,7 @@ mod tests {
     #[test]
     fn test_region_location() {
         let location = AwsRegion::AfSouth1.location();
-        assert_eq!(location, Location::new(-33.9648017883, 18.6016998291));
+        assert_eq!(location, LatLon::new(-33.9648017883, 18.6016998291));
         assert_eq!(location.latitude(), -33.9648017883);
         assert_eq!(location.longitude(), 18.6016998291);
     }
@@ -305,9 +358,9 @@ mod tests {
     #[test]
     fn test_region_distance_to() {
         let region = AwsRegion::EuWest1;
-        assert_eq!(region.distance_to(&region.location()), 0.0);
-        assert_eq!(region.distance_to(&AwsRegion::EuWest2.location()), 448_890.249);
-        assert_eq!(region.distance_to(&AwsRegion::EuWest3.location()), 784_967.795);
+        assert_eq!(region.distance_to(region.location()), 0.0);
+        assert_eq!(region.distance_to(AwsRegion::EuWest2.location()), 448_890.249);
+        assert_eq!(region.distance_to(AwsRegion::EuWest3.location()), 784_967.795);
     }
 
     #[test]
@@ -366,7 +419,7 @@ mod tests {
         ];
 
         for t in tests {
-            let region = find_region(t.latitude, t.longitude);
+            let region = find_region((t.latitude, t.longitude));
             assert_eq!(region, t.region, "{}", t.city);
         }
     }
@@ -427,7 +480,7 @@ mod tests {
         ];
 
         for t in tests {
-            let region = find_region_from_list(t.latitude, t.longitude, &t.list);
+            let region = find_region_from_list((t.latitude, t.longitude), &t.list);
             assert_eq!(region, t.region, "{}", t.city);
         }
     }
@@ -435,6 +488,42 @@ mod tests {
     #[test]
     #[should_panic(expected = "regions must not be empty")]
     fn test_find_region_from_empty_list() {
-        find_region_from_list(0, 0, &[]);
+        find_region_from_list((0, 0), &[]);
+    }
+
+    #[test]
+    fn test_region_from_name() {
+        assert_eq!(AwsRegion::from_name("us-gov-west-1"), Some(AwsRegion::UsGovWest1));
+        assert_eq!(AwsRegion::from_name("AP-SOUTHEAST-3"), Some(AwsRegion::ApSoutheast3));
+        assert_eq!(AwsRegion::from_name("us-gov-west-"), None);
+        assert_eq!(AwsRegion::from_name(""), None);
+
+        assert_eq!(aws_region!("eu-west-1"), AwsRegion::EuWest1);
+        assert_eq!(aws_region!("Sa-East-1"), AwsRegion::SaEast1);
+    }
+
+    #[test]
+    fn test_find_region_const() {
+        const ROUTES: [AwsRegion; 2] = [
+            find_region_const(LatLon::new(53.5511, 9.9937)),
+            find_region_from_list_const(
+                LatLon::new(-33.8688, 151.2093),
+                &[aws_region!("ap-southeast-1"), aws_region!("us-west-2")],
+            ),
+        ];
+        assert_eq!(ROUTES, [AwsRegion::EuCentral1, AwsRegion::ApSoutheast1]);
+
+        for latitude in (-90..=90).step_by(5) {
+            for longitude in (-180..180).step_by(5) {
+                let location = LatLon::new(latitude.into(), longitude.into());
+                assert_eq!(find_region_const(location), find_region(location), "{location:?}");
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "regions must not be empty")]
+    fn test_find_region_const_from_empty_list() {
+        find_region_from_list_const(LatLon::new(0.0, 0.0), &[]);
     }
 }
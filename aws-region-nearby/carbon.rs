@@ -0,0 +1,265 @@
+use crate::aws::AwsRegion;
+use crate::latlon::IntoLatLon;
+
+/// Round-trip distance in meters covered per millisecond, assuming signals
+/// travel through fiber at roughly two thirds of the speed of light.
+const METERS_PER_RTT_MS: f64 = 100_000.0;
+
+impl AwsRegion {
+    /// Returns the approximate carbon intensity of the electricity grid
+    /// powering the region, in grams of CO2 equivalent per kWh.
+    // Annual grid averages taken from https://app.electricitymaps.com (2023)
+    pub const fn carbon_intensity(&self) -> f64 {
+        match *self {
+            Self::AfSouth1 => 709.0,                      // South Africa
+            Self::ApEast1 => 626.0,                       // Hong Kong
+            Self::ApNortheast1 => 462.0,                  // Japan (Tokyo)
+            Self::ApNortheast2 => 415.0,                  // South Korea
+            Self::ApNortheast3 => 354.0,                  // Japan (Kansai)
+            Self::ApSouth1 => 713.0,                      // India (West)
+            Self::ApSoutheast1 => 470.0,                  // Singapore
+            Self::ApSoutheast2 => 640.0,                  // Australia (NSW)
+            Self::ApSoutheast3 => 675.0,                  // Indonesia (Java)
+            Self::CaCentral1 => 30.0,                     // Canada (Québec)
+            Self::CnNorth1 | Self::CnNorthwest1 => 582.0, // China
+            Self::EuCentral1 => 380.0,                    // Germany
+            Self::EuNorth1 => 41.0,                       // Sweden
+            Self::EuSouth1 => 331.0,                      // Italy (North)
+            Self::EuWest1 => 282.0,                       // Ireland
+            Self::EuWest2 => 238.0,                       // Great Britain
+            Self::EuWest3 => 56.0,                        // France
+            Self::MeCentral1 => 561.0,                    // United Arab Emirates
+            Self::MeSouth1 => 904.0,                      // Bahrain
+            Self::SaEast1 => 98.0,                        // Brazil (South-East)
+            Self::UsEast1 | Self::UsGovEast1 => 367.0,    // USA (PJM)
+            Self::UsEast2 => 540.0,                       // USA (Ohio)
+            Self::UsWest1 | Self::UsGovWest1 => 210.0,    // USA (California)
+            Self::UsWest2 => 130.0,                       // USA (Pacific Northwest)
+        }
+    }
+}
+
+/// How much farther than the nearest region a greener region may be.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DistanceBudget {
+    /// Extra distance in meters.
+    Meters(f64),
+
+    /// Extra estimated round-trip time in milliseconds.
+    RoundTripMillis(f64),
+}
+
+impl DistanceBudget {
+    /// Returns the budget as extra distance in meters.
+    pub fn meters(&self) -> f64 {
+        match *self {
+            Self::Meters(meters) => meters,
+            Self::RoundTripMillis(millis) => millis * METERS_PER_RTT_MS,
+        }
+    }
+}
+
+/// The result of a carbon-aware region search.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GreenerRegion {
+    /// The region with the lowest carbon intensity within the budget.
+    pub region: AwsRegion,
+
+    /// The carbon intensity of the chosen region in gCO2eq/kWh.
+    pub carbon_intensity: f64,
+
+    /// The nearest region, regardless of carbon intensity.
+    pub nearest: AwsRegion,
+
+    /// The extra distance in meters accepted over the nearest region.
+    pub extra_distance: f64,
+}
+
+/// Finds the region with the lowest carbon intensity from a list of regions.
+///
+/// Only regions that are at most `budget` farther away than the nearest one
+/// are considered. Uses the embedded [`AwsRegion::carbon_intensity`] values.
+///
+/// # Panics
+///
+/// Panics if regions is empty or the budget is negative or NaN.
+pub fn find_greener_region_from_list<L: IntoLatLon>(
+    location: L,
+    regions: &[AwsRegion],
+    budget: DistanceBudget,
+) -> GreenerRegion {
+    find_greener_region_from_list_with(location, regions, budget, AwsRegion::carbon_intensity)
+}
+
+/// Like [`find_greener_region_from_list`], but takes the carbon intensity of
+/// each region from the given function.
+///
+/// # Panics
+///
+/// Panics if regions is empty or the budget is negative or NaN.
+pub fn find_greener_region_from_list_with<L, F>(
+    location: L,
+    regions: &[AwsRegion],
+    budget: DistanceBudget,
+    carbon_intensity: F,
+) -> GreenerRegion
+where
+    L: IntoLatLon,
+    F: Fn(&AwsRegion) -> f64,
+{
+    assert!(budget.meters() >= 0.0, "budget must not be negative or NaN");
+
+    let location = location.into_lat_lon();
+    let nearest = crate::find_region_from_list(location, regions);
+    let nearest_distance = nearest.distance_to(location);
+    let max_distance = nearest_distance + budget.meters();
+
+    let (region, distance) = regions
+        .iter()
+        .map(|region| (*region, region.distance_to(location)))
+        .filter(|(_, distance)| *distance <= max_distance)
+        .min_by_key(|(region, distance)| {
+            (
+                ordered_float::OrderedFloat(carbon_intensity(region)),
+                ordered_float::OrderedFloat(*distance),
+            )
+        })
+        .expect("regions must not be empty");
+
+    GreenerRegion {
+        region,
+        carbon_intensity: carbon_intensity(&region),
+        nearest,
+        extra_distance: distance - nearest_distance,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_carbon_intensity() {
+        assert_eq!(AwsRegion::EuNorth1.carbon_intensity(), 41.0);
+        assert_eq!(
+            AwsRegion::UsGovWest1.carbon_intensity(),
+            AwsRegion::UsWest1.carbon_intensity()
+        );
+        assert!(AwsRegion::iter().all(|r| r.carbon_intensity() > 0.0));
+    }
+
+    #[test]
+    fn test_distance_budget_meters() {
+        assert_eq!(DistanceBudget::Meters(1_000.0).meters(), 1_000.0);
+        assert_eq!(DistanceBudget::RoundTripMillis(10.0).meters(), 1_000_000.0);
+    }
+
+    #[test]
+    fn test_find_greener_region_from_list() {
+        struct Test {
+            city: &'static str,
+            latitude: f64,
+            longitude: f64,
+            list: Vec<AwsRegion>,
+            budget: DistanceBudget,
+            region: AwsRegion,
+            nearest: AwsRegion,
+        }
+
+        let tests = vec![
+            Test {
+                city: "Hamburg",
+                latitude: 53.5511,
+                longitude: 9.9937,
+                list: vec![AwsRegion::EuCentral1, AwsRegion::EuWest3, AwsRegion::EuNorth1],
+                budget: DistanceBudget::Meters(0.0),
+                region: AwsRegion::EuCentral1,
+                nearest: AwsRegion::EuCentral1,
+            },
+            Test {
+                city: "Hamburg",
+                latitude: 53.5511,
+                longitude: 9.9937,
+                list: vec![AwsRegion::EuCentral1, AwsRegion::EuWest3, AwsRegion::EuNorth1],
+                budget: DistanceBudget::Meters(400_000.0),
+                region: AwsRegion::EuWest3,
+                nearest: AwsRegion::EuCentral1,
+            },
+            Test {
+                city: "Hamburg",
+                latitude: 53.5511,
+                longitude: 9.9937,
+                list: vec![AwsRegion::EuCentral1, AwsRegion::EuWest3, AwsRegion::EuNorth1],
+                budget: DistanceBudget::Meters(600_000.0),
+                region: AwsRegion::EuNorth1,
+                nearest: AwsRegion::EuCentral1,
+            },
+            Test {
+                city: "Manchester",
+                latitude: 53.4808,
+                longitude: -2.2426,
+                list: vec![AwsRegion::EuWest1, AwsRegion::EuWest2, AwsRegion::EuWest3],
+                budget: DistanceBudget::RoundTripMillis(10.0),
+                region: AwsRegion::EuWest3,
+                nearest: AwsRegion::EuWest2,
+            },
+            Test {
+                city: "Boston",
+                latitude: 42.3601,
+                longitude: -71.0589,
+                list: vec![AwsRegion::UsEast1, AwsRegion::UsEast2],
+                budget: DistanceBudget::RoundTripMillis(5.0),
+                region: AwsRegion::UsEast1,
+                nearest: AwsRegion::UsEast1,
+            },
+        ];
+
+        for t in tests {
+            let result = find_greener_region_from_list((t.latitude, t.longitude), &t.list, t.budget);
+            assert_eq!(result.region, t.region, "{}", t.city);
+            assert_eq!(result.nearest, t.nearest, "{}", t.city);
+            assert_eq!(result.carbon_intensity, t.region.carbon_intensity(), "{}", t.city);
+            assert!(result.extra_distance <= t.budget.meters(), "{}", t.city);
+        }
+    }
+
+    #[test]
+    fn test_find_greener_region_from_list_with() {
+        let location = AwsRegion::EuWest2.location();
+        let list = [AwsRegion::EuWest1, AwsRegion::EuWest2];
+
+        let result = find_greener_region_from_list_with(location, &list, DistanceBudget::Meters(500_000.0), |region| {
+            if *region == AwsRegion::EuWest1 {
+                10.0
+            } else {
+                20.0
+            }
+        });
+
+        assert_eq!(result.region, AwsRegion::EuWest1);
+        assert_eq!(result.carbon_intensity, 10.0);
+        assert_eq!(result.nearest, AwsRegion::EuWest2);
+        assert_eq!(result.extra_distance, AwsRegion::EuWest1.distance_to(location));
+    }
+
+    #[test]
+    #[should_panic(expected = "regions must not be empty")]
+    fn test_find_greener_region_from_empty_list() {
+        find_greener_region_from_list((0, 0), &[], DistanceBudget::Meters(0.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "budget must not be negative or NaN")]
+    fn test_find_greener_region_with_negative_budget() {
+        find_greener_region_from_list((0, 0), &[AwsRegion::EuWest1], DistanceBudget::Meters(-1.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "budget must not be negative or NaN")]
+    fn test_find_greener_region_with_nan_budget() {
+        find_greener_region_from_list((0, 0), &[AwsRegion::EuWest1], DistanceBudget::RoundTripMillis(f64::NAN));
+    }
+}
@@ -0,0 +1,232 @@
+use crate::airport::Airport;
+use crate::aws::{find_region_from_list, AwsRegion};
+use crate::Error;
+
+/// A CloudFront edge location (POP) and the regional edge cache behind it.
+///
+/// Lambda@Edge functions triggered at an edge location execute in the region
+/// of its regional edge cache, not at the edge location itself. CloudFront
+/// does not publish which regional edge cache serves an edge location, so the
+/// nearest one is assumed.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EdgeLocation {
+    airport: Airport,
+    regional_edge_cache: AwsRegion,
+}
+
+impl EdgeLocation {
+    /// Returns an iterator over all known CloudFront edge locations, one per
+    /// metro area.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        EDGE_LOCATIONS
+            .iter()
+            .map(|code| Self::at_airport(code.parse().expect("edge location at a known airport")))
+    }
+
+    /// Returns an iterator over the regions of all CloudFront regional edge
+    /// caches.
+    pub fn regional_edge_caches() -> impl Iterator<Item = AwsRegion> {
+        REGIONAL_EDGE_CACHES.iter().copied()
+    }
+
+    /// Parses the edge location from a POP identifier as found in the
+    /// `x-amz-cf-pop` header, e.g. `FRA56-C1`. Edge locations missing from
+    /// the catalog, as CloudFront adds new ones regularly, are accepted too.
+    pub fn from_pop(pop: &str) -> Result<Self, Error> {
+        let airport = Airport::from_pop(pop)?;
+
+        // Berlin POPs are still named after the closed Tegel airport
+        if airport.code() == "TXL" {
+            return Ok(Self::at_airport("BER".parse().expect("BER is a known airport")));
+        }
+
+        Ok(Self::at_airport(airport))
+    }
+
+    fn at_airport(airport: Airport) -> Self {
+        Self {
+            airport,
+            regional_edge_cache: airport.find_region_from_list(&REGIONAL_EDGE_CACHES),
+        }
+    }
+
+    /// Returns the airport the edge location is named after.
+    pub const fn airport(&self) -> Airport {
+        self.airport
+    }
+
+    /// Returns the region of the regional edge cache behind the edge location.
+    ///
+    /// This is an estimate: the nearest regional edge cache to the edge
+    /// location, which is usually, but not always, the one CloudFront uses.
+    pub const fn regional_edge_cache(&self) -> AwsRegion {
+        self.regional_edge_cache
+    }
+
+    /// Finds the replica region from a list of regions to use at the edge
+    /// location, e.g. from Lambda@Edge.
+    ///
+    /// This is the region of the regional edge cache if it holds a replica, as
+    /// that is where Lambda@Edge executes, and the region nearest to the edge
+    /// location otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if regions is empty.
+    pub fn find_region_from_list(&self, regions: &[AwsRegion]) -> AwsRegion {
+        if regions.contains(&self.regional_edge_cache) {
+            return self.regional_edge_cache;
+        }
+        find_region_from_list(self.airport.location(), regions)
+    }
+}
+
+/// Finds the region of the CloudFront regional edge cache behind a POP, given
+/// the value of the `x-amz-cf-pop` header, e.g. `FRA56-C1`. See
+/// [`EdgeLocation::regional_edge_cache`] for how it is estimated.
+pub fn find_regional_edge_cache(pop: &str) -> Result<AwsRegion, Error> {
+    EdgeLocation::from_pop(pop).map(|edge_location| edge_location.regional_edge_cache())
+}
+
+/// Finds the replica region from a list of regions to use at a CloudFront POP,
+/// given the value of the `x-amz-cf-pop` header. See
+/// [`EdgeLocation::find_region_from_list`].
+///
+/// # Panics
+///
+/// Panics if regions is empty.
+pub fn find_region_for_cloudfront_pop(pop: &str, regions: &[AwsRegion]) -> Result<AwsRegion, Error> {
+    let edge_location = EdgeLocation::from_pop(pop)?;
+    Ok(edge_location.find_region_from_list(regions))
+}
+
+/// Regions with a CloudFront regional edge cache.
+const REGIONAL_EDGE_CACHES: [AwsRegion; 12] = [
+    AwsRegion::ApNortheast1,
+    AwsRegion::ApNortheast2,
+    AwsRegion::ApSouth1,
+    AwsRegion::ApSoutheast1,
+    AwsRegion::ApSoutheast2,
+    AwsRegion::EuCentral1,
+    AwsRegion::EuWest1,
+    AwsRegion::EuWest2,
+    AwsRegion::SaEast1,
+    AwsRegion::UsEast1,
+    AwsRegion::UsEast2,
+    AwsRegion::UsWest2,
+];
+
+/// Airport codes of CloudFront edge locations, one per metro area.
+const EDGE_LOCATIONS: [&str; 95] = [
+    "AKL", "AMS", "ARN", "ATH", "ATL", "BAH", "BCN", "BER", "BKK", "BLR", "BNA", "BOG", "BOM", "BOS", "BRU", "BUD",
+    "CCU", "CDG", "CGK", "CMH", "CPH", "CPT", "DEL", "DEN", "DFW", "DOH", "DUB", "DUS", "DXB", "EWR", "EZE", "FCO",
+    "FOR", "FRA", "GIG", "GRU", "HAM", "HAN", "HEL", "HIO", "HKG", "HYD", "IAD", "IAH", "ICN", "JAX", "JFK", "JNB",
+    "KIX", "KUL", "LAX", "LHR", "LIM", "LIS", "LOS", "MAA", "MAD", "MAN", "MCI", "MEL", "MEX", "MIA", "MNL", "MRS",
+    "MSP", "MUC", "MXP", "NBO", "NRT", "ORD", "OSL", "OTP", "PER", "PHL", "PHX", "PMO", "PRG", "QRO", "SCL", "SEA",
+    "SFO", "SGN", "SIN", "SLC", "SOF", "SYD", "TLV", "TPE", "VIE", "WAW", "YTO", "YUL", "YVR", "ZAG", "ZRH",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_edge_location_iter() {
+        assert_eq!(EdgeLocation::iter().count(), 95);
+
+        // One per metro area
+        let mut cities: Vec<&str> = EdgeLocation::iter()
+            .map(|edge_location| edge_location.airport().city())
+            .collect();
+        cities.sort_unstable();
+        cities.dedup();
+        assert_eq!(cities.len(), 95);
+
+        assert!(EdgeLocation::iter().all(|edge_location| {
+            EdgeLocation::regional_edge_caches().any(|region| region == edge_location.regional_edge_cache())
+        }));
+    }
+
+    #[test]
+    fn test_edge_location_from_pop() {
+        let edge_location = EdgeLocation::from_pop("TXL61-P1").unwrap();
+        assert_eq!(edge_location.airport().code(), "BER");
+        assert_eq!(edge_location, EdgeLocation::from_pop("BER50-P1").unwrap());
+        assert_eq!(edge_location.regional_edge_cache(), AwsRegion::EuCentral1);
+    }
+
+    #[test]
+    fn test_find_regional_edge_cache() {
+        struct Test {
+            pop: &'static str,
+            region: Result<AwsRegion, Error>,
+        }
+
+        let tests = vec![
+            Test {
+                pop: "FRA56-C1",
+                region: Ok(AwsRegion::EuCentral1),
+            },
+            Test {
+                pop: "LAX50-C3",
+                region: Ok(AwsRegion::UsWest2),
+            },
+            Test {
+                pop: "CDG52-P1",
+                region: Ok(AwsRegion::EuWest2),
+            },
+            Test {
+                pop: "KIX56-C1",
+                region: Ok(AwsRegion::ApNortheast1),
+            },
+            // Not in the catalog, but nearest to us-east-2
+            Test {
+                pop: "AUS50-C1",
+                region: Ok(AwsRegion::UsEast2),
+            },
+            Test {
+                pop: "XYZ1-C1",
+                region: Err(Error::InvalidAirport),
+            },
+        ];
+
+        for t in tests {
+            assert_eq!(find_regional_edge_cache(t.pop), t.region, "{}", t.pop);
+        }
+    }
+
+    #[test]
+    fn test_find_region_for_cloudfront_pop() {
+        struct Test {
+            pop: &'static str,
+            list: Vec<AwsRegion>,
+            region: AwsRegion,
+        }
+
+        let tests = vec![
+            // Lambda@Edge runs in us-west-2, where a replica is available
+            Test {
+                pop: "SFO5-C1",
+                list: vec![AwsRegion::UsWest1, AwsRegion::UsWest2],
+                region: AwsRegion::UsWest2,
+            },
+            Test {
+                pop: "SFO5-C1",
+                list: vec![AwsRegion::UsEast1, AwsRegion::UsWest1],
+                region: AwsRegion::UsWest1,
+            },
+            Test {
+                pop: "MXP64-C1",
+                list: vec![AwsRegion::EuSouth1, AwsRegion::EuWest1],
+                region: AwsRegion::EuSouth1,
+            },
+        ];
+
+        for t in tests {
+            let region = find_region_for_cloudfront_pop(t.pop, &t.list);
+            assert_eq!(region, Ok(t.region), "{}", t.pop);
+        }
+    }
+}
@@ -0,0 +1,373 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::aws::{find_region_from_list, AwsRegion};
+use crate::latlon::{IntoLatLon, LatLon};
+use crate::Error;
+
+/// A validated latitude and longitude in decimal degrees.
+///
+/// Coordinates can be parsed from the notations people usually paste, e.g.
+/// `40.71,-74.00`, `40.71 N 74.00 W`, `40°42'51"N 74°00'21"W` or the
+/// `geo:40.71,-74.00` URIs of RFC 5870.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Coordinates {
+    latitude: f64,
+    longitude: f64,
+}
+
+impl Coordinates {
+    /// Creates coordinates from a latitude in the range -90 to 90 and a
+    /// longitude in the range -180 to 180.
+    pub fn new(latitude: f64, longitude: f64) -> Result<Self, Error> {
+        if (-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude) {
+            Ok(Self { latitude, longitude })
+        } else {
+            Err(Error::InvalidCoordinates)
+        }
+    }
+
+    /// Returns the latitude in decimal degrees.
+    pub const fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// Returns the longitude in decimal degrees.
+    pub const fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// Returns the location of the coordinates.
+    pub const fn location(&self) -> LatLon {
+        LatLon::new(self.latitude, self.longitude)
+    }
+
+    /// Finds the nearest AWS region to the coordinates.
+    pub fn find_region(&self) -> AwsRegion {
+        crate::find_region(self.location())
+    }
+
+    /// Finds the nearest AWS region from a list of regions to the coordinates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if regions is empty.
+    pub fn find_region_from_list(&self, regions: &[AwsRegion]) -> AwsRegion {
+        find_region_from_list(self.location(), regions)
+    }
+}
+
+impl IntoLatLon for Coordinates {
+    fn into_lat_lon(self) -> LatLon {
+        self.location()
+    }
+}
+
+impl From<Coordinates> for LatLon {
+    fn from(coordinates: Coordinates) -> Self {
+        coordinates.location()
+    }
+}
+
+impl fmt::Display for Coordinates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.latitude, self.longitude)
+    }
+}
+
+impl FromStr for Coordinates {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match s.get(..4) {
+            Some(scheme) if scheme.eq_ignore_ascii_case("geo:") => parse_geo_uri(&s[4..]),
+            _ => parse_angles(s),
+        }
+    }
+}
+
+impl TryFrom<&str> for Coordinates {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Parses the path of a `geo:` URI, e.g. `40.71,-74.00,10;u=35`. The
+/// altitude and any parameters are ignored.
+fn parse_geo_uri(path: &str) -> Result<Coordinates, Error> {
+    let path = path.split(';').next().unwrap_or_default();
+    let mut parts = path.split(',').map(|part| part.trim().parse::<f64>());
+
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(Ok(latitude)), Some(Ok(longitude)), None | Some(Ok(_)), None) => Coordinates::new(latitude, longitude),
+        _ => Err(Error::InvalidCoordinates),
+    }
+}
+
+/// Parses two angles in decimal degrees or degrees, minutes and seconds, each
+/// with an optional hemisphere, separated by whitespace, a comma or a
+/// semicolon.
+fn parse_angles(s: &str) -> Result<Coordinates, Error> {
+    let mut scanner = Scanner { rest: s };
+    let first = scanner.angle()?;
+    scanner.skip_whitespace();
+    scanner.eat(&[',', ';']);
+    let second = scanner.angle()?;
+    scanner.skip_whitespace();
+    if !scanner.rest.is_empty() {
+        return Err(Error::InvalidCoordinates);
+    }
+
+    // Without hemispheres, the latitude comes first
+    let ((latitude, _), (longitude, _)) = match (first.1, second.1) {
+        (None | Some(Axis::Latitude), None | Some(Axis::Longitude)) => (first, second),
+        (None | Some(Axis::Longitude), None | Some(Axis::Latitude)) => (second, first),
+        _ => return Err(Error::InvalidCoordinates),
+    };
+
+    Coordinates::new(latitude, longitude)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Axis {
+    Latitude,
+    Longitude,
+}
+
+const DEGREES: [char; 2] = ['°', 'º'];
+const MINUTES: [char; 3] = ['\'', '′', '’'];
+const SECONDS: [char; 3] = ['"', '″', '”'];
+
+struct Scanner<'a> {
+    rest: &'a str,
+}
+
+impl Scanner<'_> {
+    /// Scans an angle like `-40.71`, `40.71N` or `N 40°42'51.3"` and returns
+    /// its signed value in degrees and the axis given by its hemisphere.
+    fn angle(&mut self) -> Result<(f64, Option<Axis>), Error> {
+        self.skip_whitespace();
+        let prefix = self.hemisphere();
+        self.skip_whitespace();
+        let negative = self.eat(&['-', '−']).is_some();
+        if !negative {
+            self.eat(&['+']);
+        }
+
+        let mut value = self.number().ok_or(Error::InvalidCoordinates)?;
+        self.skip_whitespace();
+        if self.eat(&DEGREES).is_some() {
+            if let Some(minutes) = self.component(&MINUTES) {
+                value += minutes / 60.0;
+                if let Some(seconds) = self.component(&SECONDS) {
+                    value += seconds / 3600.0;
+                }
+            }
+        }
+
+        self.skip_whitespace();
+        let hemisphere = prefix.or_else(|| self.hemisphere());
+
+        match hemisphere {
+            Some(_) if negative => Err(Error::InvalidCoordinates),
+            Some((axis, sign)) => Ok((sign * value, Some(axis))),
+            None if negative => Ok((-value, None)),
+            None => Ok((value, None)),
+        }
+    }
+
+    /// Scans a minutes or seconds component, a number below 60 followed by one
+    /// of the given units. Leaves the input untouched if there is none.
+    fn component(&mut self, units: &[char]) -> Option<f64> {
+        let start = self.rest;
+        self.skip_whitespace();
+        let value = self.number().filter(|value| *value < 60.0);
+        // Two apostrophes are a common stand-in for the seconds symbol
+        let unit = if units == SECONDS && self.rest.starts_with("''") {
+            self.rest = &self.rest[2..];
+            true
+        } else {
+            self.eat(units).is_some()
+        };
+
+        if value.is_some() && unit {
+            value
+        } else {
+            self.rest = start;
+            None
+        }
+    }
+
+    fn hemisphere(&mut self) -> Option<(Axis, f64)> {
+        match self
+            .eat(&['N', 'n', 'S', 's', 'E', 'e', 'W', 'w'])?
+            .to_ascii_uppercase()
+        {
+            'N' => Some((Axis::Latitude, 1.0)),
+            'S' => Some((Axis::Latitude, -1.0)),
+            'E' => Some((Axis::Longitude, 1.0)),
+            _ => Some((Axis::Longitude, -1.0)),
+        }
+    }
+
+    fn number(&mut self) -> Option<f64> {
+        let end = self
+            .rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(self.rest.len());
+        let (number, rest) = self.rest.split_at(end);
+        let value = number.parse().ok()?;
+        self.rest = rest;
+        Some(value)
+    }
+
+    fn eat(&mut self, chars: &[char]) -> Option<char> {
+        let c = self.rest.chars().next().filter(|c| chars.contains(c))?;
+        self.rest = &self.rest[c.len_utf8()..];
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_coordinates_from_str() {
+        struct Test {
+            s: &'static str,
+            coordinates: Result<(f64, f64), Error>,
+        }
+
+        let tests = vec![
+            Test {
+                s: "40.71,-74.00",
+                coordinates: Ok((40.71, -74.0)),
+            },
+            Test {
+                s: " 40.71, -74 ",
+                coordinates: Ok((40.71, -74.0)),
+            },
+            Test {
+                s: "+40.71 -74",
+                coordinates: Ok((40.71, -74.0)),
+            },
+            Test {
+                s: "40.71N 74.00W",
+                coordinates: Ok((40.71, -74.0)),
+            },
+            Test {
+                s: "74.00 W, 40.71 N",
+                coordinates: Ok((40.71, -74.0)),
+            },
+            Test {
+                s: "S 33.5 E 151",
+                coordinates: Ok((-33.5, 151.0)),
+            },
+            Test {
+                s: "40°30'N 74°15'W",
+                coordinates: Ok((40.5, -74.25)),
+            },
+            Test {
+                s: "40° 30′ 36″ N, 74° 0′ 18″ W",
+                coordinates: Ok((40.51, -74.005)),
+            },
+            Test {
+                s: "40°30'36''N 74°00'18''W",
+                coordinates: Ok((40.51, -74.005)),
+            },
+            Test {
+                s: "-33.5° 151°",
+                coordinates: Ok((-33.5, 151.0)),
+            },
+            Test {
+                s: "geo:40.71,-74.00",
+                coordinates: Ok((40.71, -74.0)),
+            },
+            Test {
+                s: "GEO:40.71,-74.00,12.5;crs=wgs84;u=35",
+                coordinates: Ok((40.71, -74.0)),
+            },
+            Test {
+                s: "90,180",
+                coordinates: Ok((90.0, 180.0)),
+            },
+            Test {
+                s: "91,0",
+                coordinates: Err(Error::InvalidCoordinates),
+            },
+            Test {
+                s: "0,-180.5",
+                coordinates: Err(Error::InvalidCoordinates),
+            },
+            Test {
+                s: "74W 40W",
+                coordinates: Err(Error::InvalidCoordinates),
+            },
+            Test {
+                s: "-40.71N 74W",
+                coordinates: Err(Error::InvalidCoordinates),
+            },
+            Test {
+                s: "40°75'N 74°W",
+                coordinates: Err(Error::InvalidCoordinates),
+            },
+            Test {
+                s: "40.71",
+                coordinates: Err(Error::InvalidCoordinates),
+            },
+            Test {
+                s: "40.71,-74.00,10",
+                coordinates: Err(Error::InvalidCoordinates),
+            },
+            Test {
+                s: "geo:40.71",
+                coordinates: Err(Error::InvalidCoordinates),
+            },
+            Test {
+                s: "nan,inf",
+                coordinates: Err(Error::InvalidCoordinates),
+            },
+            Test {
+                s: "",
+                coordinates: Err(Error::InvalidCoordinates),
+            },
+        ];
+
+        for t in tests {
+            let coordinates = Coordinates::from_str(t.s).map(|c| (c.latitude(), c.longitude()));
+            match (coordinates, t.coordinates) {
+                (Ok(actual), Ok(expected)) => {
+                    assert!((actual.0 - expected.0).abs() < 1e-9, "{}: {:?}", t.s, actual);
+                    assert!((actual.1 - expected.1).abs() < 1e-9, "{}: {:?}", t.s, actual);
+                }
+                (actual, expected) => assert_eq!(actual, expected, "{}", t.s),
+            }
+        }
+    }
+
+    #[test]
+    fn test_coordinates_display() {
+        let coordinates = Coordinates::new(40.71, -74.0).unwrap();
+        assert_eq!(coordinates.to_string(), "40.71,-74");
+        assert_eq!(coordinates.to_string().parse(), Ok(coordinates));
+    }
+
+    #[test]
+    fn test_coordinates_find_region() {
+        let coordinates: Coordinates = "40°42'51\"N 74°00'21\"W".parse().unwrap();
+        assert_eq!(coordinates.find_region(), AwsRegion::UsEast1);
+        assert_eq!(
+            coordinates.find_region_from_list(&[AwsRegion::EuWest1, AwsRegion::UsWest2]),
+            AwsRegion::UsWest2
+        );
+    }
+}
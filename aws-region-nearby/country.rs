@@ -0,0 +1,464 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::aws::{find_region_from_list, AwsRegion};
+use crate::latlon::LatLon;
+use crate::Error;
+
+/// An ISO 3166-1 country.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Country {
+    code: &'static str,
+    name: &'static str,
+    latitude: f64,
+    longitude: f64,
+}
+
+impl Country {
+    /// Returns an iterator over all countries.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        COUNTRIES.iter().copied()
+    }
+
+    /// Returns the ISO 3166-1 alpha-2 code of the country, e.g. `DE`.
+    pub const fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Returns the English short name of the country.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the population-weighted centroid of the country.
+    pub const fn location(&self) -> LatLon {
+        LatLon::new(self.latitude, self.longitude)
+    }
+
+    /// Finds the AWS region from a list of regions that serves the population
+    /// of the country best.
+    ///
+    /// For countries with known [subdivisions](Self::subdivisions), this is the
+    /// region with the lowest population-weighted mean distance to them, so
+    /// that e.g. the US is not served from the region nearest to Missouri.
+    /// Otherwise it is the region nearest to the centroid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if regions is empty.
+    pub fn find_region_from_list(&self, regions: &[AwsRegion]) -> AwsRegion {
+        let subdivisions = self.subdivisions();
+        if subdivisions.is_empty() {
+            return find_region_from_list(self.location(), regions);
+        }
+
+        // The total population is the same for all regions, so comparing the
+        // weighted sums is enough and doesn't need to allocate
+        *regions
+            .iter()
+            .min_by_key(|region| {
+                let sum: f64 = subdivisions
+                    .iter()
+                    .map(|subdivision| region.distance_to(subdivision.location()) * subdivision.population())
+                    .sum();
+                ordered_float::OrderedFloat(sum)
+            })
+            .expect("regions must not be empty")
+    }
+}
+
+impl fmt::Display for Country {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code)
+    }
+}
+
+impl FromStr for Country {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        COUNTRIES
+            .iter()
+            .find(|country| country.code.eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or(Error::InvalidCountry)
+    }
+}
+
+impl TryFrom<&str> for Country {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Error> {
+        s.parse()
+    }
+}
+
+/// Finds the AWS region from a list of regions that serves a country best,
+/// given its ISO 3166-1 alpha-2 code (case-insensitive).
+///
+/// Useful when only the country of a client is known, e.g. from the
+/// `cf-ipcountry` header. See [`Country::find_region_from_list`].
+///
+/// # Panics
+///
+/// Panics if regions is empty.
+pub fn find_region_for_country(code: &str, regions: &[AwsRegion]) -> Result<AwsRegion, Error> {
+    let country: Country = code.parse()?;
+    Ok(country.find_region_from_list(regions))
+}
+
+const fn country(code: &'static str, name: &'static str, latitude: f64, longitude: f64) -> Country {
+    Country {
+        code,
+        name,
+        latitude,
+        longitude,
+    }
+}
+
+/// Approximate population-weighted centroids of all ISO 3166-1 countries. For
+/// uninhabited territories, the location of their main island or station.
+const COUNTRIES: [Country; 249] = [
+    country("AD", "Andorra", 42.51, 1.53),
+    country("AE", "United Arab Emirates", 25.0, 55.2),
+    country("AF", "Afghanistan", 34.0, 67.5),
+    country("AG", "Antigua and Barbuda", 17.1, -61.8),
+    country("AI", "Anguilla", 18.22, -63.05),
+    country("AL", "Albania", 41.2, 19.8),
+    country("AM", "Armenia", 40.2, 44.6),
+    country("AO", "Angola", -10.5, 14.8),
+    country("AQ", "Antarctica", -77.85, 166.67),
+    country("AR", "Argentina", -33.0, -61.5),
+    country("AS", "American Samoa", -14.3, -170.7),
+    country("AT", "Austria", 47.9, 14.9),
+    country("AU", "Australia", -32.5, 144.5),
+    country("AW", "Aruba", 12.52, -70.0),
+    country("AX", "Åland Islands", 60.1, 19.95),
+    country("AZ", "Azerbaijan", 40.4, 48.7),
+    country("BA", "Bosnia and Herzegovina", 44.2, 18.0),
+    country("BB", "Barbados", 13.1, -59.6),
+    country("BD", "Bangladesh", 23.8, 90.3),
+    country("BE", "Belgium", 50.8, 4.4),
+    country("BF", "Burkina Faso", 12.2, -1.6),
+    country("BG", "Bulgaria", 42.6, 24.9),
+    country("BH", "Bahrain", 26.2, 50.55),
+    country("BI", "Burundi", -3.4, 29.9),
+    country("BJ", "Benin", 7.6, 2.3),
+    country("BL", "Saint Barthélemy", 17.9, -62.83),
+    country("BM", "Bermuda", 32.3, -64.78),
+    country("BN", "Brunei", 4.8, 114.8),
+    country("BO", "Bolivia", -17.2, -65.3),
+    country("BQ", "Caribbean Netherlands", 12.18, -68.25),
+    country("BR", "Brazil", -18.0, -46.0),
+    country("BS", "Bahamas", 25.0, -77.4),
+    country("BT", "Bhutan", 27.4, 90.4),
+    country("BV", "Bouvet Island", -54.42, 3.36),
+    country("BW", "Botswana", -23.5, 25.9),
+    country("BY", "Belarus", 53.6, 27.8),
+    country("BZ", "Belize", 17.3, -88.5),
+    country("CA", "Canada", 46.7, -88.3),
+    country("CC", "Cocos (Keeling) Islands", -12.19, 96.83),
+    country("CD", "DR Congo", -3.5, 23.5),
+    country("CF", "Central African Republic", 5.0, 19.0),
+    country("CG", "Congo", -4.4, 14.6),
+    country("CH", "Switzerland", 47.1, 8.2),
+    country("CI", "Côte d'Ivoire", 6.5, -5.0),
+    country("CK", "Cook Islands", -21.2, -159.8),
+    country("CL", "Chile", -34.0, -71.0),
+    country("CM", "Cameroon", 5.5, 11.5),
+    country("CN", "China", 32.0, 113.5),
+    country("CO", "Colombia", 5.5, -74.6),
+    country("CR", "Costa Rica", 9.9, -84.1),
+    country("CU", "Cuba", 22.0, -79.0),
+    country("CV", "Cabo Verde", 15.0, -23.6),
+    country("CW", "Curaçao", 12.17, -68.98),
+    country("CX", "Christmas Island", -10.42, 105.68),
+    country("CY", "Cyprus", 35.0, 33.2),
+    country("CZ", "Czechia", 49.9, 15.3),
+    country("DE", "Germany", 50.9, 9.4),
+    country("DJ", "Djibouti", 11.6, 43.1),
+    country("DK", "Denmark", 55.8, 11.0),
+    country("DM", "Dominica", 15.4, -61.35),
+    country("DO", "Dominican Republic", 18.7, -70.2),
+    country("DZ", "Algeria", 35.5, 3.5),
+    country("EC", "Ecuador", -1.3, -79.2),
+    country("EE", "Estonia", 58.9, 25.0),
+    country("EG", "Egypt", 29.8, 31.3),
+    country("EH", "Western Sahara", 27.1, -13.2),
+    country("ER", "Eritrea", 15.3, 38.9),
+    country("ES", "Spain", 39.8, -3.7),
+    country("ET", "Ethiopia", 8.7, 38.8),
+    country("FI", "Finland", 61.2, 25.0),
+    country("FJ", "Fiji", -17.8, 178.2),
+    country("FK", "Falkland Islands", -51.7, -57.85),
+    country("FM", "Micronesia", 6.9, 158.2),
+    country("FO", "Faroe Islands", 62.0, -6.8),
+    country("FR", "France", 47.0, 2.5),
+    country("GA", "Gabon", 0.2, 10.0),
+    country("GB", "United Kingdom", 52.6, -1.6),
+    country("GD", "Grenada", 12.1, -61.7),
+    country("GE", "Georgia", 41.9, 44.0),
+    country("GF", "French Guiana", 4.9, -52.5),
+    country("GG", "Guernsey", 49.45, -2.55),
+    country("GH", "Ghana", 6.8, -1.3),
+    country("GI", "Gibraltar", 36.14, -5.35),
+    country("GL", "Greenland", 64.2, -51.7),
+    country("GM", "Gambia", 13.4, -16.2),
+    country("GN", "Guinea", 10.0, -11.5),
+    country("GP", "Guadeloupe", 16.2, -61.55),
+    country("GQ", "Equatorial Guinea", 2.5, 9.6),
+    country("GR", "Greece", 38.6, 23.2),
+    country("GS", "South Georgia and the South Sandwich Islands", -54.28, -36.5),
+    country("GT", "Guatemala", 14.9, -90.9),
+    country("GU", "Guam", 13.47, 144.78),
+    country("GW", "Guinea-Bissau", 11.9, -15.3),
+    country("GY", "Guyana", 6.6, -58.2),
+    country("HK", "Hong Kong", 22.35, 114.15),
+    country("HM", "Heard Island and McDonald Islands", -53.1, 73.5),
+    country("HN", "Honduras", 14.8, -87.5),
+    country("HR", "Croatia", 45.4, 16.4),
+    country("HT", "Haiti", 18.8, -72.5),
+    country("HU", "Hungary", 47.3, 19.3),
+    country("ID", "Indonesia", -5.0, 110.0),
+    country("IE", "Ireland", 53.2, -7.3),
+    country("IL", "Israel", 32.0, 34.9),
+    country("IM", "Isle of Man", 54.2, -4.5),
+    country("IN", "India", 23.3, 79.8),
+    country("IO", "British Indian Ocean Territory", -7.3, 72.4),
+    country("IQ", "Iraq", 33.5, 44.3),
+    country("IR", "Iran", 33.5, 51.5),
+    country("IS", "Iceland", 64.1, -21.8),
+    country("IT", "Italy", 42.7, 12.0),
+    country("JE", "Jersey", 49.2, -2.13),
+    country("JM", "Jamaica", 18.05, -77.1),
+    country("JO", "Jordan", 31.9, 35.95),
+    country("JP", "Japan", 35.4, 137.4),
+    country("KE", "Kenya", -0.7, 36.5),
+    country("KG", "Kyrgyzstan", 41.7, 73.9),
+    country("KH", "Cambodia", 12.0, 104.8),
+    country("KI", "Kiribati", 1.45, 173.0),
+    country("KM", "Comoros", -11.7, 43.4),
+    country("KN", "Saint Kitts and Nevis", 17.3, -62.7),
+    country("KP", "North Korea", 39.5, 126.5),
+    country("KR", "South Korea", 36.9, 127.4),
+    country("KW", "Kuwait", 29.3, 47.9),
+    country("KY", "Cayman Islands", 19.3, -81.3),
+    country("KZ", "Kazakhstan", 46.5, 71.0),
+    country("LA", "Laos", 18.3, 103.4),
+    country("LB", "Lebanon", 33.85, 35.6),
+    country("LC", "Saint Lucia", 13.95, -60.98),
+    country("LI", "Liechtenstein", 47.15, 9.53),
+    country("LK", "Sri Lanka", 7.1, 80.1),
+    country("LR", "Liberia", 6.6, -10.2),
+    country("LS", "Lesotho", -29.4, 27.8),
+    country("LT", "Lithuania", 55.1, 24.1),
+    country("LU", "Luxembourg", 49.6, 6.1),
+    country("LV", "Latvia", 56.9, 24.4),
+    country("LY", "Libya", 32.0, 15.5),
+    country("MA", "Morocco", 33.0, -7.0),
+    country("MC", "Monaco", 43.74, 7.42),
+    country("MD", "Moldova", 47.1, 28.6),
+    country("ME", "Montenegro", 42.6, 19.3),
+    country("MF", "Saint Martin", 18.07, -63.05),
+    country("MG", "Madagascar", -19.0, 47.2),
+    country("MH", "Marshall Islands", 7.1, 171.2),
+    country("MK", "North Macedonia", 41.8, 21.6),
+    country("ML", "Mali", 13.5, -6.5),
+    country("MM", "Myanmar", 19.0, 96.0),
+    country("MN", "Mongolia", 47.9, 106.9),
+    country("MO", "Macao", 22.19, 113.54),
+    country("MP", "Northern Mariana Islands", 15.2, 145.75),
+    country("MQ", "Martinique", 14.6, -61.0),
+    country("MR", "Mauritania", 18.5, -15.0),
+    country("MS", "Montserrat", 16.75, -62.2),
+    country("MT", "Malta", 35.9, 14.45),
+    country("MU", "Mauritius", -20.25, 57.55),
+    country("MV", "Maldives", 4.18, 73.5),
+    country("MW", "Malawi", -14.5, 34.3),
+    country("MX", "Mexico", 20.8, -100.3),
+    country("MY", "Malaysia", 3.5, 104.0),
+    country("MZ", "Mozambique", -17.5, 36.0),
+    country("NA", "Namibia", -19.8, 16.5),
+    country("NC", "New Caledonia", -22.2, 166.45),
+    country("NE", "Niger", 14.0, 6.5),
+    country("NF", "Norfolk Island", -29.04, 167.95),
+    country("NG", "Nigeria", 8.5, 7.0),
+    country("NI", "Nicaragua", 12.5, -86.0),
+    country("NL", "Netherlands", 52.1, 5.2),
+    country("NO", "Norway", 60.5, 9.5),
+    country("NP", "Nepal", 27.5, 84.8),
+    country("NR", "Nauru", -0.53, 166.93),
+    country("NU", "Niue", -19.05, -169.87),
+    country("NZ", "New Zealand", -38.8, 175.0),
+    country("OM", "Oman", 23.3, 57.6),
+    country("PA", "Panama", 8.8, -79.7),
+    country("PE", "Peru", -11.0, -76.5),
+    country("PF", "French Polynesia", -17.6, -149.5),
+    country("PG", "Papua New Guinea", -6.3, 145.0),
+    country("PH", "Philippines", 12.5, 122.5),
+    country("PK", "Pakistan", 29.5, 71.2),
+    country("PL", "Poland", 51.9, 19.4),
+    country("PM", "Saint Pierre and Miquelon", 46.78, -56.18),
+    country("PN", "Pitcairn Islands", -25.07, -130.1),
+    country("PR", "Puerto Rico", 18.3, -66.3),
+    country("PS", "Palestine", 31.8, 34.9),
+    country("PT", "Portugal", 39.7, -8.5),
+    country("PW", "Palau", 7.45, 134.55),
+    country("PY", "Paraguay", -25.5, -57.0),
+    country("QA", "Qatar", 25.3, 51.45),
+    country("RE", "Réunion", -21.1, 55.5),
+    country("RO", "Romania", 45.6, 25.2),
+    country("RS", "Serbia", 44.3, 20.6),
+    country("RU", "Russia", 54.5, 49.5),
+    country("RW", "Rwanda", -2.0, 30.0),
+    country("SA", "Saudi Arabia", 23.5, 44.5),
+    country("SB", "Solomon Islands", -9.4, 160.0),
+    country("SC", "Seychelles", -4.65, 55.45),
+    country("SD", "Sudan", 14.5, 31.5),
+    country("SE", "Sweden", 58.6, 15.6),
+    country("SG", "Singapore", 1.35, 103.82),
+    country("SH", "Saint Helena, Ascension and Tristan da Cunha", -15.93, -5.72),
+    country("SI", "Slovenia", 46.1, 14.8),
+    country("SJ", "Svalbard and Jan Mayen", 78.22, 15.65),
+    country("SK", "Slovakia", 48.6, 19.0),
+    country("SL", "Sierra Leone", 8.4, -12.2),
+    country("SM", "San Marino", 43.94, 12.46),
+    country("SN", "Senegal", 14.5, -16.0),
+    country("SO", "Somalia", 4.5, 45.5),
+    country("SR", "Suriname", 5.8, -55.2),
+    country("SS", "South Sudan", 6.8, 30.5),
+    country("ST", "São Tomé and Príncipe", 0.3, 6.7),
+    country("SV", "El Salvador", 13.7, -89.2),
+    country("SX", "Sint Maarten", 18.04, -63.06),
+    country("SY", "Syria", 34.8, 37.0),
+    country("SZ", "Eswatini", -26.5, 31.4),
+    country("TC", "Turks and Caicos Islands", 21.78, -72.26),
+    country("TD", "Chad", 11.0, 17.0),
+    country("TF", "French Southern Territories", -49.35, 70.2),
+    country("TG", "Togo", 7.3, 1.1),
+    country("TH", "Thailand", 14.8, 101.2),
+    country("TJ", "Tajikistan", 38.6, 69.1),
+    country("TK", "Tokelau", -9.2, -171.8),
+    country("TL", "Timor-Leste", -8.7, 125.8),
+    country("TM", "Turkmenistan", 38.7, 60.0),
+    country("TN", "Tunisia", 36.0, 10.1),
+    country("TO", "Tonga", -21.15, -175.2),
+    country("TR", "Türkiye", 39.6, 31.8),
+    country("TT", "Trinidad and Tobago", 10.55, -61.3),
+    country("TV", "Tuvalu", -8.52, 179.2),
+    country("TW", "Taiwan", 24.2, 120.9),
+    country("TZ", "Tanzania", -5.3, 35.8),
+    country("UA", "Ukraine", 49.0, 31.3),
+    country("UG", "Uganda", 0.9, 32.5),
+    country("UM", "United States Minor Outlying Islands", 19.28, 166.65),
+    country("US", "United States", 37.42, -92.39),
+    country("UY", "Uruguay", -34.5, -56.0),
+    country("UZ", "Uzbekistan", 40.6, 68.8),
+    country("VA", "Vatican City", 41.9, 12.45),
+    country("VC", "Saint Vincent and the Grenadines", 13.2, -61.2),
+    country("VE", "Venezuela", 9.5, -67.4),
+    country("VG", "British Virgin Islands", 18.43, -64.62),
+    country("VI", "U.S. Virgin Islands", 18.34, -64.9),
+    country("VN", "Vietnam", 16.0, 106.5),
+    country("VU", "Vanuatu", -16.8, 168.0),
+    country("WF", "Wallis and Futuna", -13.3, -176.2),
+    country("WS", "Samoa", -13.8, -171.8),
+    country("YE", "Yemen", 14.8, 44.8),
+    country("YT", "Mayotte", -12.8, 45.15),
+    country("ZA", "South Africa", -27.5, 27.8),
+    country("ZM", "Zambia", -13.8, 28.5),
+    country("ZW", "Zimbabwe", -18.6, 30.6),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_country_from_str() {
+        let country: Country = "de".parse().unwrap();
+        assert_eq!(country.code(), "DE");
+        assert_eq!(country.name(), "Germany");
+        assert_eq!(country.to_string(), "DE");
+
+        assert_eq!(Country::try_from("GB").unwrap().name(), "United Kingdom");
+        assert_eq!("ZZ".parse::<Country>(), Err(Error::InvalidCountry));
+        assert_eq!("DEU".parse::<Country>(), Err(Error::InvalidCountry));
+    }
+
+    #[test]
+    fn test_country_iter() {
+        assert_eq!(Country::iter().count(), 249);
+        assert!(Country::iter().all(|country| country.code().len() == 2));
+        assert!(Country::iter().all(|country| {
+            let location = country.location();
+            (-90.0..=90.0).contains(&location.latitude()) && (-180.0..=180.0).contains(&location.longitude())
+        }));
+
+        // Sorted and unique
+        let codes: Vec<&str> = Country::iter().map(|country| country.code()).collect();
+        assert!(codes.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_find_region_for_country() {
+        struct Test {
+            code: &'static str,
+            list: Vec<AwsRegion>,
+            region: AwsRegion,
+        }
+
+        let tests = vec![
+            Test {
+                code: "DE",
+                list: vec![AwsRegion::EuWest1, AwsRegion::EuCentral1, AwsRegion::EuNorth1],
+                region: AwsRegion::EuCentral1,
+            },
+            Test {
+                code: "ie",
+                list: vec![AwsRegion::EuWest1, AwsRegion::EuWest2],
+                region: AwsRegion::EuWest1,
+            },
+            Test {
+                code: "NZ",
+                list: vec![AwsRegion::ApSoutheast1, AwsRegion::ApSoutheast2, AwsRegion::UsWest1],
+                region: AwsRegion::ApSoutheast2,
+            },
+            // The centroid of the US is in Missouri, but most people live
+            // nearer to the east coast
+            Test {
+                code: "US",
+                list: vec![AwsRegion::UsEast1, AwsRegion::UsWest2],
+                region: AwsRegion::UsEast1,
+            },
+            // The centroid of Canada is in Lake Superior
+            Test {
+                code: "CA",
+                list: vec![AwsRegion::UsEast2, AwsRegion::CaCentral1],
+                region: AwsRegion::CaCentral1,
+            },
+            Test {
+                code: "AU",
+                list: vec![AwsRegion::ApSoutheast1, AwsRegion::ApSoutheast2],
+                region: AwsRegion::ApSoutheast2,
+            },
+        ];
+
+        for t in tests {
+            let region = find_region_for_country(t.code, &t.list);
+            assert_eq!(region, Ok(t.region), "{}", t.code);
+        }
+
+        assert_eq!(
+            find_region_for_country("T1", &[AwsRegion::EuWest1]),
+            Err(Error::InvalidCountry)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "regions must not be empty")]
+    fn test_find_region_for_country_from_empty_list() {
+        find_region_for_country("US", &[]).unwrap();
+    }
+}
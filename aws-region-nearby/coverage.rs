@@ -0,0 +1,301 @@
+use crate::aws::{find_region_from_list, AwsRegion};
+use crate::latlon::{IntoLatLon, EARTH_RADIUS};
+use crate::stats::weighted_percentile;
+
+/// A sampled location and the replica serving it.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoverageSample {
+    /// The latitude of the sample (the cell center for grids).
+    pub latitude: f64,
+
+    /// The longitude of the sample (the cell center for grids).
+    pub longitude: f64,
+
+    /// The nearest replica region.
+    pub region: AwsRegion,
+
+    /// The distance in meters to the nearest replica region.
+    pub distance: f64,
+}
+
+/// How much of the sampled area or locations a replica region serves.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RegionCoverage {
+    /// The replica region.
+    pub region: AwsRegion,
+
+    /// The fraction (0-1) of all samples, by weight, served by the region.
+    pub share: f64,
+
+    /// The area in square meters served by the region. Only known when
+    /// sampling a grid.
+    pub area: Option<f64>,
+}
+
+/// Nearest-replica distance percentiles in meters.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoveragePercentiles {
+    /// The median distance.
+    pub p50: f64,
+
+    /// The 90th percentile distance.
+    pub p90: f64,
+
+    /// The 95th percentile distance.
+    pub p95: f64,
+
+    /// The 99th percentile distance.
+    pub p99: f64,
+
+    /// The maximum distance.
+    pub max: f64,
+}
+
+/// The result of a coverage analysis.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoverageReport {
+    /// Distance percentiles over all samples, weighted by area for grids.
+    pub percentiles: CoveragePercentiles,
+
+    /// The worst-served samples, farthest first.
+    pub worst: Vec<CoverageSample>,
+
+    /// The coverage of each replica region, in the order they were given.
+    pub regions: Vec<RegionCoverage>,
+}
+
+/// Analyzes how well a list of replica regions covers the Earth by sampling a
+/// global grid with cells of `step` degrees.
+///
+/// Samples are weighted by the area of their cell, so that the report is not
+/// skewed towards the poles. Up to `worst` of the worst-served cells are
+/// included in the report.
+///
+/// # Panics
+///
+/// Panics if regions is empty or step is not a finite positive number.
+pub fn analyze_coverage(regions: &[AwsRegion], step: f64, worst: usize) -> CoverageReport {
+    assert!(step.is_finite() && step > 0.0, "step must be finite and positive");
+
+    let rows = (180.0 / step).ceil() as usize;
+    let cols = (360.0 / step).ceil() as usize;
+
+    let mut samples = Vec::with_capacity(rows * cols);
+    for row in 0..rows {
+        let south = (row as f64).mul_add(step, -90.0).min(90.0);
+        let north = (south + step).min(90.0);
+        let latitude = (south + north) / 2.0;
+
+        for col in 0..cols {
+            let west = (col as f64).mul_add(step, -180.0);
+            let east = (west + step).min(180.0);
+            let longitude = (west + east) / 2.0;
+
+            let area = EARTH_RADIUS.powi(2)
+                * (east - west).to_radians()
+                * (north.to_radians().sin() - south.to_radians().sin());
+
+            samples.push((latitude, longitude, area));
+        }
+    }
+
+    let mut report = analyze(&samples, regions, worst);
+    let total: f64 = samples.iter().map(|(_, _, area)| area).sum();
+    for coverage in &mut report.regions {
+        coverage.area = Some(coverage.share * total);
+    }
+    report
+}
+
+/// Analyzes how well a list of replica regions covers the given locations,
+/// e.g. a sample of real user locations.
+///
+/// Up to `worst` of the worst-served locations are included in the report.
+///
+/// # Panics
+///
+/// Panics if regions or locations is empty.
+pub fn analyze_coverage_of_locations<L: IntoLatLon + Copy>(
+    locations: &[L],
+    regions: &[AwsRegion],
+    worst: usize,
+) -> CoverageReport {
+    assert!(!locations.is_empty(), "locations must not be empty");
+
+    let samples: Vec<(f64, f64, f64)> = locations
+        .iter()
+        .map(|location| {
+            let location = location.into_lat_lon();
+            (location.latitude(), location.longitude(), 1.0)
+        })
+        .collect();
+
+    analyze(&samples, regions, worst)
+}
+
+fn analyze(samples: &[(f64, f64, f64)], regions: &[AwsRegion], worst: usize) -> CoverageReport {
+    let served: Vec<CoverageSample> = samples
+        .iter()
+        .map(|&(latitude, longitude, _)| {
+            let region = find_region_from_list((latitude, longitude), regions);
+            let distance = region.distance_to((latitude, longitude));
+            CoverageSample {
+                latitude,
+                longitude,
+                region,
+                distance,
+            }
+        })
+        .collect();
+
+    let distances: Vec<f64> = served.iter().map(|sample| sample.distance).collect();
+    let weights: Vec<f64> = samples.iter().map(|(_, _, weight)| *weight).collect();
+    let total: f64 = weights.iter().sum();
+
+    let percentiles = CoveragePercentiles {
+        p50: weighted_percentile(&distances, &weights, 50.0),
+        p90: weighted_percentile(&distances, &weights, 90.0),
+        p95: weighted_percentile(&distances, &weights, 95.0),
+        p99: weighted_percentile(&distances, &weights, 99.0),
+        max: weighted_percentile(&distances, &weights, 100.0),
+    };
+
+    let mut coverage: Vec<RegionCoverage> = Vec::new();
+    for region in regions_in_order(regions) {
+        let weight: f64 = served
+            .iter()
+            .zip(&weights)
+            .filter(|(sample, _)| sample.region == region)
+            .map(|(_, weight)| weight)
+            .sum();
+        coverage.push(RegionCoverage {
+            region,
+            share: weight / total,
+            area: None,
+        });
+    }
+
+    let mut worst_served = served;
+    worst_served.sort_by(|a, b| b.distance.total_cmp(&a.distance));
+    worst_served.truncate(worst);
+
+    CoverageReport {
+        percentiles,
+        worst: worst_served,
+        regions: coverage,
+    }
+}
+
+/// Returns the given regions without duplicates, keeping their order.
+fn regions_in_order(regions: &[AwsRegion]) -> Vec<AwsRegion> {
+    let mut unique = Vec::with_capacity(regions.len());
+    for region in regions {
+        if !unique.contains(region) {
+            unique.push(*region);
+        }
+    }
+    unique
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_analyze_coverage() {
+        let replicas = [AwsRegion::EuCentral1, AwsRegion::UsEast1, AwsRegion::ApNortheast1];
+
+        let report = analyze_coverage(&replicas, 10.0, 5);
+        assert_eq!(report.worst.len(), 5);
+        assert!(report.worst.windows(2).all(|w| w[0].distance >= w[1].distance));
+        assert_eq!(report.worst[0].distance, report.percentiles.max);
+        assert!(report.percentiles.p50 <= report.percentiles.p90);
+        assert!(report.percentiles.p90 <= report.percentiles.p95);
+        assert!(report.percentiles.p95 <= report.percentiles.p99);
+        assert!(report.percentiles.p99 <= report.percentiles.max);
+
+        // The southern hemisphere is poorly served by these replicas
+        assert!(report.worst.iter().all(|sample| sample.latitude < 0.0));
+
+        let regions: Vec<AwsRegion> = report.regions.iter().map(|coverage| coverage.region).collect();
+        assert_eq!(regions, replicas.to_vec());
+
+        let share: f64 = report.regions.iter().map(|coverage| coverage.share).sum();
+        assert!((share - 1.0).abs() < 1e-9);
+
+        let area: f64 = report.regions.iter().map(|coverage| coverage.area.unwrap()).sum();
+        let earth = 4.0 * std::f64::consts::PI * EARTH_RADIUS.powi(2);
+        assert!((area - earth).abs() / earth < 1e-9);
+    }
+
+    #[test]
+    fn test_analyze_coverage_adding_replica() {
+        let before = analyze_coverage(&[AwsRegion::EuCentral1, AwsRegion::UsEast1], 15.0, 1);
+        let after = analyze_coverage(
+            &[AwsRegion::EuCentral1, AwsRegion::UsEast1, AwsRegion::SaEast1],
+            15.0,
+            1,
+        );
+        assert!(after.percentiles.p95 < before.percentiles.p95);
+        assert!(after.percentiles.max <= before.percentiles.max);
+    }
+
+    #[test]
+    fn test_analyze_coverage_of_locations() {
+        let locations = [
+            (53.5511, 9.9937),    // Hamburg
+            (53.4808, -2.2426),   // Manchester
+            (42.3601, -71.0589),  // Boston
+            (-33.8688, 151.2093), // Sydney
+        ];
+        let replicas = [AwsRegion::EuWest2, AwsRegion::EuCentral1, AwsRegion::UsEast1];
+
+        let report = analyze_coverage_of_locations(&locations, &replicas, 1);
+        assert_eq!(report.worst.len(), 1);
+        assert_eq!(report.worst[0].latitude, -33.8688);
+        assert_eq!(report.percentiles.max, report.worst[0].distance);
+        assert_eq!(
+            report.regions,
+            vec![
+                RegionCoverage {
+                    region: AwsRegion::EuWest2,
+                    share: 0.25,
+                    area: None,
+                },
+                RegionCoverage {
+                    region: AwsRegion::EuCentral1,
+                    share: 0.25,
+                    area: None,
+                },
+                RegionCoverage {
+                    region: AwsRegion::UsEast1,
+                    share: 0.5,
+                    area: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "regions must not be empty")]
+    fn test_analyze_coverage_of_empty_list() {
+        analyze_coverage(&[], 30.0, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be finite and positive")]
+    fn test_analyze_coverage_invalid_step() {
+        analyze_coverage(&[AwsRegion::EuWest1], 0.0, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be finite and positive")]
+    fn test_analyze_coverage_infinite_step() {
+        analyze_coverage(&[AwsRegion::EuWest1], f64::INFINITY, 1);
+    }
+}
@@ -1,9 +1,8 @@
-use std::fmt;
-use std::str::FromStr;
-
-use geoutils::Location;
+use core::fmt;
+use core::str::FromStr;
 
 use crate::aws::AwsRegion;
+use crate::latlon::{IntoLatLon, LatLon};
 use crate::Error;
 
 /// A Deno Deploy region. Based on <https://deno.com/deploy/docs/regions>
@@ -203,49 +202,49 @@ impl DenoRegion {
     /// Returns the location of the region.
     // Coordinates taken from https://simplemaps.com/data/world-cities
     // TODO: share city coordinates with AwsRegion
-    pub const fn location(&self) -> Location {
+    pub const fn location(&self) -> LatLon {
         match *self {
-            Self::AsiaEast1 => Location::new_const(25.0478, 121.5319), // assuming Taipei, Taiwan
-            Self::AsiaEast2 => Location::new_const(22.3069, 114.1831), // Hong Kong, China
-            Self::AsiaNortheast1 => Location::new_const(35.6839, 139.7744), // Tokyo, Japan
-            Self::AsiaNortheast2 => Location::new_const(34.7520, 135.4582), // Osaka, Japan
-            Self::AsiaNortheast3 => Location::new_const(37.5600, 126.9900), // Seoul, South Korea
-            Self::AsiaSouth1 => Location::new_const(19.0758, 72.8775), // Mumbai, India
-            Self::AsiaSouth2 => Location::new_const(28.6667, 77.2167), // Delhi, India
-            Self::AsiaSoutheast1 => Location::new_const(1.3000, 103.8000), // Singapore
-            Self::AsiaSoutheast2 => Location::new_const(-6.2146, 106.8451), // Jakarta, Indonesia
-            Self::AustraliaSoutheast1 => Location::new_const(-33.8650, 151.2094), // Sydney, Australia
-            Self::AustraliaSoutheast2 => Location::new_const(-37.8136, 144.963), // Melbourne, Australia
-            Self::EuropeCentral2 => Location::new_const(52.2300, 21.0111), // Warsaw, Poland
-            Self::EuropeNorth1 => Location::new_const(60.1756, 24.9342), // assuming Helsinki, Finland
-            Self::EuropeWest1 => Location::new_const(50.8353, 4.3314), // assuming Brussels, Belgium
-            Self::EuropeWest2 => Location::new_const(51.5072, -0.1275), // London, United Kingdom
-            Self::EuropeWest3 => Location::new_const(50.1136, 8.6797), // Frankfurt, Germany
-            Self::EuropeWest4 => Location::new_const(52.3667, 4.8833), // assuming Amsterdam, Netherlands
-            Self::EuropeWest6 => Location::new_const(47.3744, 8.5411), // Zurich, Switzerland
-            Self::EuropeWest8 => Location::new_const(45.4669, 9.1900), // Milan, Italy
-            Self::EuropeWest9 => Location::new_const(48.8566, 2.3522), // Paris, France
-            Self::MeWest1 => Location::new_const(32.0800, 34.7800),    // Tel Aviv, Israel
-            Self::EuropeSouthwest1 => Location::new_const(40.4167, -3.7167), // Madrid, Spain
-            Self::NorthamericaNortheast1 => Location::new_const(45.5089, -73.5617), // Montréal, Canada
-            Self::NorthamericaNortheast2 => Location::new_const(43.7417, -79.3733), // Toronto, Canada
-            Self::SouthamericaEast1 => Location::new_const(-23.5504, -46.6339), // São Paulo, Brazil
-            Self::SouthamericaWest1 => Location::new_const(-33.4500, -70.6667), // assuming Santiago, Chile
-            Self::UsCentral1 => Location::new_const(41.5725, -93.6105), // assuming Des Moines, IA
-            Self::UsEast1 => Location::new_const(34.0376, -80.9037),   // assuming Columbia, SC
-            Self::UsEast4 => Location::new_const(39.0300, -77.4711),   // assuming Ashburn, VA
-            Self::UsEast5 => Location::new_const(39.9862, -82.9850),   // assuming Columbus, OH
-            Self::UsSouth1 => Location::new_const(30.3004, -97.7522),  // assuming Austin, TX
-            Self::UsWest1 => Location::new_const(45.5272, -122.9361),  // assuming Hillsboro, OR
-            Self::UsWest2 => Location::new_const(37.7562, -122.4430),  // assuming San Francisco, CA
-            Self::UsWest3 => Location::new_const(40.7777, -111.9306),  // assuming Salt Lake City, UT
-            Self::UsWest4 => Location::new_const(39.1512, -119.7474),  // assuming Carson City, NV
+            Self::AsiaEast1 => LatLon::new(25.0478, 121.5319), // assuming Taipei, Taiwan
+            Self::AsiaEast2 => LatLon::new(22.3069, 114.1831), // Hong Kong, China
+            Self::AsiaNortheast1 => LatLon::new(35.6839, 139.7744), // Tokyo, Japan
+            Self::AsiaNortheast2 => LatLon::new(34.7520, 135.4582), // Osaka, Japan
+            Self::AsiaNortheast3 => LatLon::new(37.5600, 126.9900), // Seoul, South Korea
+            Self::AsiaSouth1 => LatLon::new(19.0758, 72.8775), // Mumbai, India
+            Self::AsiaSouth2 => LatLon::new(28.6667, 77.2167), // Delhi, India
+            Self::AsiaSoutheast1 => LatLon::new(1.3000, 103.8000), // Singapore
+            Self::AsiaSoutheast2 => LatLon::new(-6.2146, 106.8451), // Jakarta, Indonesia
+            Self::AustraliaSoutheast1 => LatLon::new(-33.8650, 151.2094), // Sydney, Australia
+            Self::AustraliaSoutheast2 => LatLon::new(-37.8136, 144.963), // Melbourne, Australia
+            Self::EuropeCentral2 => LatLon::new(52.2300, 21.0111), // Warsaw, Poland
+            Self::EuropeNorth1 => LatLon::new(60.1756, 24.9342), // assuming Helsinki, Finland
+            Self::EuropeWest1 => LatLon::new(50.8353, 4.3314), // assuming Brussels, Belgium
+            Self::EuropeWest2 => LatLon::new(51.5072, -0.1275), // London, United Kingdom
+            Self::EuropeWest3 => LatLon::new(50.1136, 8.6797), // Frankfurt, Germany
+            Self::EuropeWest4 => LatLon::new(52.3667, 4.8833), // assuming Amsterdam, Netherlands
+            Self::EuropeWest6 => LatLon::new(47.3744, 8.5411), // Zurich, Switzerland
+            Self::EuropeWest8 => LatLon::new(45.4669, 9.1900), // Milan, Italy
+            Self::EuropeWest9 => LatLon::new(48.8566, 2.3522), // Paris, France
+            Self::MeWest1 => LatLon::new(32.0800, 34.7800),    // Tel Aviv, Israel
+            Self::EuropeSouthwest1 => LatLon::new(40.4167, -3.7167), // Madrid, Spain
+            Self::NorthamericaNortheast1 => LatLon::new(45.5089, -73.5617), // Montréal, Canada
+            Self::NorthamericaNortheast2 => LatLon::new(43.7417, -79.3733), // Toronto, Canada
+            Self::SouthamericaEast1 => LatLon::new(-23.5504, -46.6339), // São Paulo, Brazil
+            Self::SouthamericaWest1 => LatLon::new(-33.4500, -70.6667), // assuming Santiago, Chile
+            Self::UsCentral1 => LatLon::new(41.5725, -93.6105), // assuming Des Moines, IA
+            Self::UsEast1 => LatLon::new(34.0376, -80.9037),   // assuming Columbia, SC
+            Self::UsEast4 => LatLon::new(39.0300, -77.4711),   // assuming Ashburn, VA
+            Self::UsEast5 => LatLon::new(39.9862, -82.9850),   // assuming Columbus, OH
+            Self::UsSouth1 => LatLon::new(30.3004, -97.7522),  // assuming Austin, TX
+            Self::UsWest1 => LatLon::new(45.5272, -122.9361),  // assuming Hillsboro, OR
+            Self::UsWest2 => LatLon::new(37.7562, -122.4430),  // assuming San Francisco, CA
+            Self::UsWest3 => LatLon::new(40.7777, -111.9306),  // assuming Salt Lake City, UT
+            Self::UsWest4 => LatLon::new(39.1512, -119.7474),  // assuming Carson City, NV
         }
     }
 
     /// Returns the distance in meters between the region and the given location.
-    pub fn distance_to(&self, to: &Location) -> f64 {
-        self.location().haversine_distance_to(to).meters()
+    pub fn distance_to<L: IntoLatLon>(&self, to: L) -> f64 {
+        self.location().distance_to(to)
     }
 
     /// Finds the nearest AWS region from a list of regions.
@@ -254,13 +253,13 @@ impl DenoRegion {
     ///
     /// Panics if regions is empty.
     pub fn find_region_from_list(&self, regions: &[AwsRegion]) -> AwsRegion {
-        crate::find_region_from_list(self.location().latitude(), self.location().longitude(), regions)
+        crate::find_region_from_list(self.location(), regions)
     }
 }
 
 impl From<DenoRegion> for AwsRegion {
     fn from(region: DenoRegion) -> Self {
-        crate::find_region(region.location().latitude(), region.location().longitude())
+        crate::find_region(region.location())
     }
 }
 
@@ -274,44 +273,9 @@ impl FromStr for DenoRegion {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Error> {
-        match s.to_lowercase().as_ref() {
-            "asia-east1" => Ok(Self::AsiaEast1),
-            "asia-east2" => Ok(Self::AsiaEast2),
-            "asia-northeast1" => Ok(Self::AsiaNortheast1),
-            "asia-northeast2" => Ok(Self::AsiaNortheast2),
-            "asia-northeast3" => Ok(Self::AsiaNortheast3),
-            "asia-south1" => Ok(Self::AsiaSouth1),
-            "asia-south2" => Ok(Self::AsiaSouth2),
-            "asia-southeast1" => Ok(Self::AsiaSoutheast1),
-            "asia-southeast2" => Ok(Self::AsiaSoutheast2),
-            "australia-southeast1" => Ok(Self::AustraliaSoutheast1),
-            "australia-southeast2" => Ok(Self::AustraliaSoutheast2),
-            "europe-central2" => Ok(Self::EuropeCentral2),
-            "europe-north1" => Ok(Self::EuropeNorth1),
-            "europe-west1" => Ok(Self::EuropeWest1),
-            "europe-west2" => Ok(Self::EuropeWest2),
-            "europe-west3" => Ok(Self::EuropeWest3),
-            "europe-west4" => Ok(Self::EuropeWest4),
-            "europe-west6" => Ok(Self::EuropeWest6),
-            "europe-west8" => Ok(Self::EuropeWest8),
-            "europe-west9" => Ok(Self::EuropeWest9),
-            "me-west1" => Ok(Self::MeWest1),
-            "europe-southwest1" => Ok(Self::EuropeSouthwest1),
-            "northamerica-northeast1" => Ok(Self::NorthamericaNortheast1),
-            "northamerica-northeast2" => Ok(Self::NorthamericaNortheast2),
-            "southamerica-east1" => Ok(Self::SouthamericaEast1),
-            "southamerica-west1" => Ok(Self::SouthamericaWest1),
-            "us-central1" => Ok(Self::UsCentral1),
-            "us-east1" => Ok(Self::UsEast1),
-            "us-east4" => Ok(Self::UsEast4),
-            "us-east5" => Ok(Self::UsEast5),
-            "us-south1" => Ok(Self::UsSouth1),
-            "us-west1" => Ok(Self::UsWest1),
-            "us-west2" => Ok(Self::UsWest2),
-            "us-west3" => Ok(Self::UsWest3),
-            "us-west4" => Ok(Self::UsWest4),
-            _ => Err(Error::InvalidDenoRegion),
-        }
+        Self::iter()
+            .find(|region| region.name().eq_ignore_ascii_case(s))
+            .ok_or(Error::InvalidDenoRegion)
     }
 }
 
@@ -352,7 +316,7 @@ mod tests {
     #[test]
     fn test_region_location() {
         let location = DenoRegion::AsiaNortheast2.location();
-        assert_eq!(location, Location::new(34.7520, 135.4582));
+        assert_eq!(location, LatLon::new(34.7520, 135.4582));
         assert_eq!(location.latitude(), 34.7520);
         assert_eq!(location.longitude(), 135.4582);
     }
@@ -360,9 +324,9 @@ mod tests {
     #[test]
     fn test_region_distance_to() {
         let region = DenoRegion::EuropeNorth1;
-        assert_eq!(region.distance_to(&region.location()), 0.0);
-        assert_eq!(region.distance_to(&AwsRegion::EuWest2.location()), 1_840_385.671);
-        assert_eq!(region.distance_to(&AwsRegion::EuWest3.location()), 1_886_010.429);
+        assert_eq!(region.distance_to(region.location()), 0.0);
+        assert_eq!(region.distance_to(AwsRegion::EuWest2.location()), 1_840_385.671);
+        assert_eq!(region.distance_to(AwsRegion::EuWest3.location()), 1_886_010.429);
     }
 
     #[test]
@@ -0,0 +1,323 @@
+use core::fmt;
+
+use crate::aws::AwsRegion;
+
+/// An AWS partition, i.e. a group of regions sharing DNS suffixes and
+/// credentials.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Partition {
+    /// The standard partition.
+    Aws,
+
+    /// The China partition.
+    AwsCn,
+
+    /// The AWS GovCloud (US) partition.
+    AwsUsGov,
+}
+
+impl Partition {
+    /// Returns the name of the partition, e.g. `aws-cn`.
+    pub const fn name(&self) -> &'static str {
+        match *self {
+            Self::Aws => "aws",
+            Self::AwsCn => "aws-cn",
+            Self::AwsUsGov => "aws-us-gov",
+        }
+    }
+
+    /// Returns the DNS suffix of service endpoints in the partition.
+    pub const fn dns_suffix(&self) -> &'static str {
+        match *self {
+            Self::Aws | Self::AwsUsGov => "amazonaws.com",
+            Self::AwsCn => "amazonaws.com.cn",
+        }
+    }
+
+    /// Returns the DNS suffix of dual-stack (IPv4 and IPv6) service endpoints
+    /// in the partition.
+    pub const fn dual_stack_dns_suffix(&self) -> &'static str {
+        match *self {
+            Self::Aws | Self::AwsUsGov => "api.aws",
+            Self::AwsCn => "api.amazonwebservices.com.cn",
+        }
+    }
+}
+
+impl fmt::Display for Partition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl AwsRegion {
+    /// Returns the partition of the region.
+    pub const fn partition(&self) -> Partition {
+        match *self {
+            Self::CnNorth1 | Self::CnNorthwest1 => Partition::AwsCn,
+            Self::UsGovEast1 | Self::UsGovWest1 => Partition::AwsUsGov,
+            _ => Partition::Aws,
+        }
+    }
+
+    /// Returns the endpoint of a service in the region, e.g. `dynamodb`.
+    ///
+    /// ```
+    /// use aws_region_nearby::AwsRegion;
+    ///
+    /// let endpoint = AwsRegion::CnNorth1.endpoint("dynamodb");
+    /// assert_eq!(endpoint.to_string(), "https://dynamodb.cn-north-1.amazonaws.com.cn");
+    ///
+    /// let endpoint = AwsRegion::UsEast1.endpoint("s3").fips(true).dual_stack(true);
+    /// assert_eq!(endpoint.to_string(), "https://s3-fips.dualstack.us-east-1.amazonaws.com");
+    /// ```
+    pub const fn endpoint<'a>(&self, service: &'a str) -> Endpoint<'a> {
+        Endpoint {
+            region: *self,
+            service,
+            fips: false,
+            dual_stack: false,
+            legacy_global: false,
+            dns_suffix: None,
+        }
+    }
+}
+
+/// The endpoint of an AWS service in a region, which formats as its HTTPS
+/// URL.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Endpoint<'a> {
+    region: AwsRegion,
+    service: &'a str,
+    fips: bool,
+    dual_stack: bool,
+    legacy_global: bool,
+    dns_suffix: Option<&'a str>,
+}
+
+impl<'a> Endpoint<'a> {
+    /// Uses the FIPS 140-2 validated endpoint. The endpoints of GovCloud
+    /// global services are always FIPS validated.
+    pub const fn fips(mut self, fips: bool) -> Self {
+        self.fips = fips;
+        self
+    }
+
+    /// Uses the dual-stack (IPv4 and IPv6) endpoint. Global services have no
+    /// dual-stack endpoints, so this is ignored for them.
+    pub const fn dual_stack(mut self, dual_stack: bool) -> Self {
+        self.dual_stack = dual_stack;
+        self
+    }
+
+    /// Uses the legacy global endpoint of services that have since become
+    /// regional: `s3.amazonaws.com` for S3 in us-east-1 and
+    /// `sts.amazonaws.com` for STS in the standard partition.
+    pub const fn legacy_global(mut self, legacy_global: bool) -> Self {
+        self.legacy_global = legacy_global;
+        self
+    }
+
+    /// Overrides the DNS suffix of the partition, e.g. for VPC endpoints or
+    /// partitions not known to this crate.
+    pub const fn dns_suffix(mut self, dns_suffix: &'a str) -> Self {
+        self.dns_suffix = Some(dns_suffix);
+        self
+    }
+
+    /// Returns the region of the endpoint.
+    pub const fn region(&self) -> AwsRegion {
+        self.region
+    }
+
+    /// Returns the service of the endpoint.
+    pub const fn service(&self) -> &'a str {
+        self.service
+    }
+
+    /// Returns the hostname of the endpoint.
+    #[cfg(feature = "std")]
+    pub fn host(&self) -> String {
+        let mut host = String::new();
+        self.write_host(&mut host).expect("writing to a string");
+        host
+    }
+
+    fn write_host<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        let partition = self.region.partition();
+        let region = self.region.name();
+        let service = self.service;
+        let fips = if self.fips { "-fips" } else { "" };
+
+        if let Some(host) = self.global_host(partition) {
+            let suffix = self.dns_suffix.unwrap_or_else(|| partition.dns_suffix());
+            return match host {
+                GlobalHost::Global => write!(w, "{service}{fips}.{suffix}"),
+                GlobalHost::Partition(name) => write!(w, "{service}.{name}.{suffix}"),
+                GlobalHost::Region(region) => write!(w, "{service}.{}.{suffix}", region.name()),
+            };
+        }
+
+        // S3 predates the partition-wide dual-stack suffix
+        if service == "s3" && self.dual_stack {
+            let suffix = self.dns_suffix.unwrap_or_else(|| partition.dns_suffix());
+            return write!(w, "s3{fips}.dualstack.{region}.{suffix}");
+        }
+
+        let suffix = self.dns_suffix.unwrap_or_else(|| {
+            if self.dual_stack {
+                partition.dual_stack_dns_suffix()
+            } else {
+                partition.dns_suffix()
+            }
+        });
+        write!(w, "{service}{fips}.{region}.{suffix}")
+    }
+
+    /// Returns where the endpoint of a global service lives, if it is one.
+    fn global_host(&self, partition: Partition) -> Option<GlobalHost> {
+        let legacy = self.legacy_global && !self.fips && !self.dual_stack;
+
+        match (self.service, partition) {
+            ("iam" | "route53", Partition::AwsUsGov) => Some(GlobalHost::Partition("us-gov")),
+            ("iam", Partition::AwsCn) => Some(GlobalHost::Region(AwsRegion::CnNorth1)),
+            ("cloudfront", Partition::AwsCn) => Some(GlobalHost::Region(AwsRegion::CnNorthwest1)),
+            ("iam" | "route53" | "cloudfront", _) => Some(GlobalHost::Global),
+            ("s3", _) if legacy && self.region == AwsRegion::UsEast1 => Some(GlobalHost::Global),
+            ("sts", Partition::Aws) if legacy => Some(GlobalHost::Global),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Endpoint<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("https://")?;
+        self.write_host(f)
+    }
+}
+
+/// Where the single endpoint of a global service lives.
+enum GlobalHost {
+    /// Directly under the DNS suffix, e.g. `iam.amazonaws.com`.
+    Global,
+
+    /// Under a partition label, e.g. `iam.us-gov.amazonaws.com`.
+    Partition(&'static str),
+
+    /// In a fixed region, e.g. `iam.cn-north-1.amazonaws.com.cn`.
+    Region(AwsRegion),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_partition() {
+        assert_eq!(AwsRegion::EuWest1.partition(), Partition::Aws);
+        assert_eq!(AwsRegion::CnNorthwest1.partition(), Partition::AwsCn);
+        assert_eq!(AwsRegion::UsGovEast1.partition(), Partition::AwsUsGov);
+        assert_eq!(Partition::AwsUsGov.to_string(), "aws-us-gov");
+    }
+
+    #[test]
+    fn test_endpoint() {
+        struct Test {
+            endpoint: Endpoint<'static>,
+            host: &'static str,
+        }
+
+        let tests = vec![
+            Test {
+                endpoint: AwsRegion::EuWest1.endpoint("dynamodb"),
+                host: "dynamodb.eu-west-1.amazonaws.com",
+            },
+            Test {
+                endpoint: AwsRegion::CnNorth1.endpoint("sqs"),
+                host: "sqs.cn-north-1.amazonaws.com.cn",
+            },
+            Test {
+                endpoint: AwsRegion::UsGovWest1.endpoint("dynamodb").fips(true),
+                host: "dynamodb-fips.us-gov-west-1.amazonaws.com",
+            },
+            Test {
+                endpoint: AwsRegion::EuCentral1.endpoint("sqs").dual_stack(true),
+                host: "sqs.eu-central-1.api.aws",
+            },
+            Test {
+                endpoint: AwsRegion::CnNorthwest1.endpoint("sqs").dual_stack(true),
+                host: "sqs.cn-northwest-1.api.amazonwebservices.com.cn",
+            },
+            Test {
+                endpoint: AwsRegion::UsEast2.endpoint("lambda").fips(true).dual_stack(true),
+                host: "lambda-fips.us-east-2.api.aws",
+            },
+            Test {
+                endpoint: AwsRegion::ApSoutheast2.endpoint("s3").dual_stack(true),
+                host: "s3.dualstack.ap-southeast-2.amazonaws.com",
+            },
+            Test {
+                endpoint: AwsRegion::UsEast1.endpoint("s3"),
+                host: "s3.us-east-1.amazonaws.com",
+            },
+            Test {
+                endpoint: AwsRegion::UsEast1.endpoint("s3").legacy_global(true),
+                host: "s3.amazonaws.com",
+            },
+            Test {
+                endpoint: AwsRegion::UsEast1.endpoint("s3").legacy_global(true).fips(true),
+                host: "s3-fips.us-east-1.amazonaws.com",
+            },
+            Test {
+                endpoint: AwsRegion::EuWest1.endpoint("s3").legacy_global(true),
+                host: "s3.eu-west-1.amazonaws.com",
+            },
+            Test {
+                endpoint: AwsRegion::ApSouth1.endpoint("sts").legacy_global(true),
+                host: "sts.amazonaws.com",
+            },
+            Test {
+                endpoint: AwsRegion::CnNorth1.endpoint("sts").legacy_global(true),
+                host: "sts.cn-north-1.amazonaws.com.cn",
+            },
+            Test {
+                endpoint: AwsRegion::EuWest2.endpoint("iam").dual_stack(true),
+                host: "iam.amazonaws.com",
+            },
+            Test {
+                endpoint: AwsRegion::UsEast1.endpoint("iam").fips(true),
+                host: "iam-fips.amazonaws.com",
+            },
+            Test {
+                endpoint: AwsRegion::UsGovWest1.endpoint("route53"),
+                host: "route53.us-gov.amazonaws.com",
+            },
+            Test {
+                endpoint: AwsRegion::CnNorthwest1.endpoint("iam"),
+                host: "iam.cn-north-1.amazonaws.com.cn",
+            },
+            Test {
+                endpoint: AwsRegion::CnNorth1.endpoint("cloudfront"),
+                host: "cloudfront.cn-northwest-1.amazonaws.com.cn",
+            },
+            Test {
+                endpoint: AwsRegion::EuWest1.endpoint("dynamodb").dns_suffix("example.com"),
+                host: "dynamodb.eu-west-1.example.com",
+            },
+        ];
+
+        for t in tests {
+            assert_eq!(
+                t.endpoint.to_string(),
+                format!("https://{}", t.host),
+                "{:?}",
+                t.endpoint
+            );
+            #[cfg(feature = "std")]
+            assert_eq!(t.endpoint.host(), t.host);
+        }
+    }
+}
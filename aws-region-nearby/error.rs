@@ -1,5 +1,6 @@
 /// The errors returned by the library.
 #[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Error {
     /// An invalid AWS region name was provided.
     #[deprecated(note = "please use `InvalidAwsRegion` instead")]
@@ -10,8 +11,61 @@ pub enum Error {
     #[error("invalid AWS region")]
     InvalidAwsRegion,
 
+    /// An unknown AWS Local Zone or Wavelength Zone was provided.
+    #[error("invalid AWS zone")]
+    InvalidAwsZone,
+
+    /// An invalid or unknown Availability Zone ID was provided.
+    #[error("invalid availability zone ID")]
+    InvalidAvailabilityZone,
+
     /// An invalid Deno Deploy region name was provided.
     #[cfg(feature = "deno")]
     #[error("invalid Deno Deploy region")]
     InvalidDenoRegion,
+
+    /// An invalid or unknown ISO 3166-1 country code was provided.
+    #[error("invalid country code")]
+    InvalidCountry,
+
+    /// An invalid ISO 3166-2 subdivision code was provided.
+    #[error("invalid subdivision code")]
+    InvalidSubdivision,
+
+    /// An unknown IANA time zone, or one without a location, was provided.
+    #[error("invalid time zone")]
+    InvalidTimezone,
+
+    /// An unknown IATA airport code, or a POP identifier not starting with
+    /// one, was provided.
+    #[error("invalid airport code")]
+    InvalidAirport,
+
+    /// Coordinates that could not be parsed, or a latitude or longitude out
+    /// of range, were provided.
+    #[error("invalid coordinates")]
+    InvalidCoordinates,
+
+    /// An invalid geohash was provided.
+    #[error("invalid geohash")]
+    InvalidGeohash,
+
+    /// An unknown AWS service name was provided.
+    #[error("invalid service")]
+    InvalidService,
+
+    /// The city could not be found in the gazetteer.
+    #[cfg(feature = "gazetteer")]
+    #[error("city not found")]
+    UnknownCity,
+
+    /// The GeoIP database could not be read.
+    #[cfg(feature = "maxmind")]
+    #[error("invalid GeoIP database: {0}")]
+    InvalidGeoIpDatabase(String),
+
+    /// The IP address could not be found in the GeoIP database.
+    #[cfg(feature = "maxmind")]
+    #[error("IP address not found in GeoIP database")]
+    UnknownIpAddress,
 }
@@ -0,0 +1,585 @@
+use std::cmp::Reverse;
+
+use ordered_float::OrderedFloat;
+
+use crate::aws::{find_region_from_list, AwsRegion};
+use crate::country::Country;
+use crate::latlon::LatLon;
+use crate::Error;
+
+/// A major world city.
+///
+/// With the `serde` feature, a city deserializes from its name and country
+/// via [`City::find`]; other fields are ignored.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct City {
+    name: &'static str,
+    country: &'static str,
+    latitude: f64,
+    longitude: f64,
+    population: f64,
+}
+
+impl City {
+    /// Returns an iterator over all cities in the gazetteer.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        CITIES.iter().copied()
+    }
+
+    /// Finds a city by name.
+    ///
+    /// Matching ignores case, accents, spaces and punctuation, tolerates small
+    /// typos, and knows common alternative names like `Bombay`. The name can
+    /// be qualified with a country code or name, e.g. `Portland, US`. If more
+    /// than one city matches equally well, the most populous one wins.
+    pub fn find(query: &str) -> Result<Self, Error> {
+        let (name, country) = match query.rsplit_once(',') {
+            Some((name, country)) if !is_alias(query) => (name, Some(normalize(country))),
+            _ => (query, None),
+        };
+        let name = normalize(name);
+        if name.is_empty() {
+            return Err(Error::UnknownCity);
+        }
+        let name = CITY_ALIASES
+            .iter()
+            .find(|(alias, _)| normalize(alias) == name)
+            .map_or(name, |(_, city)| normalize(city));
+
+        // Allow one typo per four letters
+        let max_distance = name.chars().count() / 4;
+
+        CITIES
+            .iter()
+            .filter(|city| {
+                country.as_ref().is_none_or(|country| {
+                    let code = city.country();
+                    *country == normalize(code.code()) || *country == normalize(code.name())
+                })
+            })
+            .map(|city| (city, edit_distance(&normalize(city.name), &name)))
+            .filter(|(_, distance)| *distance <= max_distance)
+            .min_by_key(|(city, distance)| (*distance, Reverse(OrderedFloat(city.population))))
+            .map(|(city, _)| *city)
+            .ok_or(Error::UnknownCity)
+    }
+
+    /// Returns the English name of the city.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the country of the city.
+    pub fn country(&self) -> Country {
+        self.country.parse().expect("city in a known country")
+    }
+
+    /// Returns the location of the city center.
+    pub const fn location(&self) -> LatLon {
+        LatLon::new(self.latitude, self.longitude)
+    }
+
+    /// Returns the approximate population of the metropolitan area, in
+    /// millions.
+    pub const fn population(&self) -> f64 {
+        self.population
+    }
+
+    /// Finds the nearest AWS region from a list of regions to the city.
+    ///
+    /// # Panics
+    ///
+    /// Panics if regions is empty.
+    pub fn find_region_from_list(&self, regions: &[AwsRegion]) -> AwsRegion {
+        find_region_from_list(self.location(), regions)
+    }
+}
+
+/// Finds the nearest AWS region from a list of regions to a city, given its
+/// name, e.g. `São Paulo` or `Sao Paulo`. See [`City::find`].
+///
+/// Returns the region together with the matched city, so that a fuzzy match
+/// can be checked.
+///
+/// # Panics
+///
+/// Panics if regions is empty.
+pub fn find_region_for_city(name: &str, regions: &[AwsRegion]) -> Result<(AwsRegion, City), Error> {
+    let city = City::find(name)?;
+    Ok((city.find_region_from_list(regions), city))
+}
+
+fn is_alias(query: &str) -> bool {
+    let query = normalize(query);
+    CITY_ALIASES.iter().any(|(alias, _)| normalize(alias) == query)
+}
+
+/// Lowercases the name and strips accents and everything that is not a letter
+/// or digit, e.g. `Saint-Étienne` becomes `saintetienne`.
+fn normalize(name: &str) -> String {
+    let mut normalized = String::with_capacity(name.len());
+    for c in name.chars().flat_map(char::to_lowercase) {
+        match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => normalized.push('a'),
+            'ç' | 'ć' | 'č' => normalized.push('c'),
+            'ď' | 'đ' => normalized.push('d'),
+            'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => normalized.push('e'),
+            'ğ' => normalized.push('g'),
+            'ì' | 'í' | 'î' | 'ï' | 'ī' | 'ı' => normalized.push('i'),
+            'ł' => normalized.push('l'),
+            'ñ' | 'ń' | 'ň' => normalized.push('n'),
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => normalized.push('o'),
+            'ř' => normalized.push('r'),
+            'ś' | 'š' | 'ş' | 'ș' => normalized.push('s'),
+            'ţ' | 'ț' | 'ť' => normalized.push('t'),
+            'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' => normalized.push('u'),
+            'ý' | 'ÿ' => normalized.push('y'),
+            'ź' | 'ż' | 'ž' => normalized.push('z'),
+            'ß' => normalized.push_str("ss"),
+            'æ' => normalized.push_str("ae"),
+            'œ' => normalized.push_str("oe"),
+            c if c.is_alphanumeric() => normalized.push(c),
+            _ => {}
+        }
+    }
+    normalized
+}
+
+/// Returns the Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+const fn city(name: &'static str, country: &'static str, latitude: f64, longitude: f64, population: f64) -> City {
+    City {
+        name,
+        country,
+        latitude,
+        longitude,
+        population,
+    }
+}
+
+/// Alternative names of cities, e.g. former or local ones.
+const CITY_ALIASES: [(&str, &str); 21] = [
+    ("Bengaluru", "Bangalore"),
+    ("Bombay", "Mumbai"),
+    ("Calcutta", "Kolkata"),
+    ("Canton", "Guangzhou"),
+    ("Kiev", "Kyiv"),
+    ("Köln", "Cologne"),
+    ("Lisboa", "Lisbon"),
+    ("Madras", "Chennai"),
+    ("Milano", "Milan"),
+    ("Moskva", "Moscow"),
+    ("München", "Munich"),
+    ("NYC", "New York"),
+    ("New York City", "New York"),
+    ("Peking", "Beijing"),
+    ("Praha", "Prague"),
+    ("Rangoon", "Yangon"),
+    ("Roma", "Rome"),
+    ("Saigon", "Ho Chi Minh City"),
+    ("St. Petersburg", "Saint Petersburg"),
+    ("Washington, D.C.", "Washington"),
+    ("Wien", "Vienna"),
+];
+
+/// Metropolitan areas of around a million people or more, plus smaller
+/// capitals and cloud hubs.
+static CITIES: [City; 278] = [
+    city("Tokyo", "JP", 35.6762, 139.6503, 37.2),
+    city("Delhi", "IN", 28.7041, 77.1025, 32.9),
+    city("Shanghai", "CN", 31.2304, 121.4737, 29.2),
+    city("Dhaka", "BD", 23.8103, 90.4125, 23.2),
+    city("São Paulo", "BR", -23.5505, -46.6333, 22.6),
+    city("Cairo", "EG", 30.0444, 31.2357, 22.2),
+    city("Mexico City", "MX", 19.4326, -99.1332, 22.3),
+    city("Beijing", "CN", 39.9042, 116.4074, 21.8),
+    city("Mumbai", "IN", 19.0760, 72.8777, 21.3),
+    city("Osaka", "JP", 34.6937, 135.5023, 19.0),
+    city("Chongqing", "CN", 29.4316, 106.9123, 17.3),
+    city("Karachi", "PK", 24.8607, 67.0011, 17.2),
+    city("Kinshasa", "CD", -4.4419, 15.2663, 16.3),
+    city("Lagos", "NG", 6.5244, 3.3792, 15.9),
+    city("Istanbul", "TR", 41.0082, 28.9784, 15.8),
+    city("Buenos Aires", "AR", -34.6037, -58.3816, 15.4),
+    city("Kolkata", "IN", 22.5726, 88.3639, 15.3),
+    city("Manila", "PH", 14.5995, 120.9842, 14.7),
+    city("Guangzhou", "CN", 23.1291, 113.2644, 14.3),
+    city("Tianjin", "CN", 39.3434, 117.3616, 14.0),
+    city("Lahore", "PK", 31.5204, 74.3587, 13.9),
+    city("Bangalore", "IN", 12.9716, 77.5946, 13.6),
+    city("Rio de Janeiro", "BR", -22.9068, -43.1729, 13.7),
+    city("Shenzhen", "CN", 22.5431, 114.0579, 13.1),
+    city("Moscow", "RU", 55.7558, 37.6173, 12.7),
+    city("Chennai", "IN", 13.0827, 80.2707, 11.8),
+    city("Bogotá", "CO", 4.7110, -74.0721, 11.5),
+    city("Jakarta", "ID", -6.2088, 106.8456, 11.2),
+    city("Lima", "PE", -12.0464, -77.0428, 11.2),
+    city("Paris", "FR", 48.8566, 2.3522, 11.1),
+    city("Bangkok", "TH", 13.7563, 100.5018, 11.1),
+    city("Hyderabad", "IN", 17.3850, 78.4867, 10.8),
+    city("Seoul", "KR", 37.5665, 126.9780, 10.0),
+    city("Nagoya", "JP", 35.1815, 136.9066, 9.5),
+    city("London", "GB", 51.5072, -0.1276, 9.6),
+    city("Chengdu", "CN", 30.5728, 104.0668, 9.5),
+    city("Tehran", "IR", 35.6892, 51.3890, 9.5),
+    city("Nanjing", "CN", 32.0603, 118.7969, 9.4),
+    city("Ho Chi Minh City", "VN", 10.8231, 106.6297, 9.3),
+    city("Luanda", "AO", -8.8390, 13.2894, 9.0),
+    city("Wuhan", "CN", 30.5928, 114.3055, 8.6),
+    city("Xi'an", "CN", 34.3416, 108.9398, 8.5),
+    city("Ahmedabad", "IN", 23.0225, 72.5714, 8.5),
+    city("Kuala Lumpur", "MY", 3.1390, 101.6869, 8.4),
+    city("New York", "US", 40.7128, -74.0060, 8.3),
+    city("Hangzhou", "CN", 30.2741, 120.1551, 8.2),
+    city("Hong Kong", "HK", 22.3193, 114.1694, 7.7),
+    city("Dongguan", "CN", 23.0207, 113.7518, 7.6),
+    city("Foshan", "CN", 23.0215, 113.1214, 7.5),
+    city("Riyadh", "SA", 24.7136, 46.6753, 7.5),
+    city("Baghdad", "IQ", 33.3152, 44.3661, 7.5),
+    city("Santiago", "CL", -33.4489, -70.6693, 6.9),
+    city("Surat", "IN", 21.1702, 72.8311, 7.8),
+    city("Madrid", "ES", 40.4168, -3.7038, 6.8),
+    city("Suzhou", "CN", 31.2990, 120.5853, 6.7),
+    city("Pune", "IN", 18.5204, 73.8567, 7.0),
+    city("Harbin", "CN", 45.8038, 126.5350, 6.5),
+    city("Houston", "US", 29.7604, -95.3698, 6.4),
+    city("Dallas", "US", 32.7767, -96.7970, 6.5),
+    city("Toronto", "CA", 43.6532, -79.3832, 6.3),
+    city("Dar es Salaam", "TZ", -6.7924, 39.2083, 7.4),
+    city("Miami", "US", 25.7617, -80.1918, 6.1),
+    city("Belo Horizonte", "BR", -19.9167, -43.9345, 6.2),
+    city("Singapore", "SG", 1.3521, 103.8198, 5.9),
+    city("Philadelphia", "US", 39.9526, -75.1652, 5.7),
+    city("Atlanta", "US", 33.7490, -84.3880, 5.9),
+    city("Fukuoka", "JP", 33.5904, 130.4017, 5.5),
+    city("Khartoum", "SD", 15.5007, 32.5599, 6.2),
+    city("Barcelona", "ES", 41.3874, 2.1686, 5.6),
+    city("Johannesburg", "ZA", -26.2041, 28.0473, 6.2),
+    city("Saint Petersburg", "RU", 59.9311, 30.3609, 5.5),
+    city("Qingdao", "CN", 36.0671, 120.3826, 5.6),
+    city("Dalian", "CN", 38.9140, 121.6147, 5.4),
+    city("Washington", "US", 38.9072, -77.0369, 5.4),
+    city("Yangon", "MM", 16.8409, 96.1735, 5.6),
+    city("Alexandria", "EG", 31.2001, 29.9187, 5.5),
+    city("Jinan", "CN", 36.6512, 117.1201, 5.0),
+    city("Guadalajara", "MX", 20.6597, -103.3496, 5.3),
+    city("Chicago", "US", 41.8781, -87.6298, 8.9),
+    city("Los Angeles", "US", 34.0522, -118.2437, 12.5),
+    city("Abidjan", "CI", 5.3600, -4.0083, 5.5),
+    city("Ankara", "TR", 39.9334, 32.8597, 5.3),
+    city("Chittagong", "BD", 22.3569, 91.7832, 5.3),
+    city("Melbourne", "AU", -37.8136, 144.9631, 5.1),
+    city("Sydney", "AU", -33.8688, 151.2093, 5.3),
+    city("Monterrey", "MX", 25.6866, -100.3161, 5.1),
+    city("Nairobi", "KE", -1.2921, 36.8219, 5.3),
+    city("Hanoi", "VN", 21.0278, 105.8342, 5.1),
+    city("Brasília", "BR", -15.7975, -47.8919, 4.8),
+    city("Cape Town", "ZA", -33.9249, 18.4241, 4.8),
+    city("Jeddah", "SA", 21.4858, 39.1925, 4.8),
+    city("Kabul", "AF", 34.5553, 69.2075, 4.6),
+    city("Casablanca", "MA", 33.5731, -7.5898, 3.8),
+    city("Boston", "US", 42.3601, -71.0589, 4.9),
+    city("Phoenix", "US", 33.4484, -112.0740, 4.9),
+    city("San Francisco", "US", 37.7749, -122.4194, 4.7),
+    city("Berlin", "DE", 52.5200, 13.4050, 4.6),
+    city("Algiers", "DZ", 36.7538, 3.0588, 2.9),
+    city("Accra", "GH", 5.6037, -0.1870, 2.6),
+    city("Addis Ababa", "ET", 9.0300, 38.7400, 5.2),
+    city("Fortaleza", "BR", -3.7319, -38.5267, 4.1),
+    city("Recife", "BR", -8.0476, -34.8770, 4.2),
+    city("Porto Alegre", "BR", -30.0346, -51.2177, 4.3),
+    city("Salvador", "BR", -12.9777, -38.5016, 3.9),
+    city("Curitiba", "BR", -25.4284, -49.2733, 3.7),
+    city("Medellín", "CO", 6.2442, -75.5812, 4.1),
+    city("Caracas", "VE", 10.4806, -66.9036, 2.9),
+    city("Montreal", "CA", 45.5017, -73.5673, 4.3),
+    city("Vancouver", "CA", 49.2827, -123.1207, 2.6),
+    city("Calgary", "CA", 51.0447, -114.0719, 1.5),
+    city("Ottawa", "CA", 45.4215, -75.6972, 1.4),
+    city("Seattle", "US", 47.6062, -122.3321, 4.0),
+    city("Detroit", "US", 42.3314, -83.0458, 4.3),
+    city("Minneapolis", "US", 44.9778, -93.2650, 3.7),
+    city("San Diego", "US", 32.7157, -117.1611, 3.3),
+    city("Tampa", "US", 27.9506, -82.4572, 3.2),
+    city("Denver", "US", 39.7392, -104.9903, 2.9),
+    city("St. Louis", "US", 38.6270, -90.1994, 2.8),
+    city("Baltimore", "US", 39.2904, -76.6122, 2.8),
+    city("Orlando", "US", 28.5383, -81.3792, 2.7),
+    city("Charlotte", "US", 35.2271, -80.8431, 2.7),
+    city("San Antonio", "US", 29.4241, -98.4936, 2.6),
+    city("Portland", "US", 45.5152, -122.6784, 2.5),
+    city("Sacramento", "US", 38.5816, -121.4944, 2.4),
+    city("Pittsburgh", "US", 40.4406, -79.9959, 2.4),
+    city("Austin", "US", 30.2672, -97.7431, 2.4),
+    city("Las Vegas", "US", 36.1699, -115.1398, 2.3),
+    city("Cincinnati", "US", 39.1031, -84.5120, 2.3),
+    city("Kansas City", "US", 39.0997, -94.5786, 2.2),
+    city("Columbus", "US", 39.9612, -82.9988, 2.1),
+    city("Indianapolis", "US", 39.7684, -86.1581, 2.1),
+    city("Cleveland", "US", 41.4993, -81.6944, 2.1),
+    city("San Jose", "US", 37.3382, -121.8863, 2.0),
+    city("Nashville", "US", 36.1627, -86.7816, 2.0),
+    city("Salt Lake City", "US", 40.7608, -111.8910, 1.3),
+    city("Honolulu", "US", 21.3069, -157.8583, 1.0),
+    city("Anchorage", "US", 61.2181, -149.9003, 0.4),
+    city("Brisbane", "AU", -27.4698, 153.0251, 2.6),
+    city("Perth", "AU", -31.9505, 115.8605, 2.1),
+    city("Adelaide", "AU", -34.9285, 138.6007, 1.4),
+    city("Auckland", "NZ", -36.8485, 174.7633, 1.7),
+    city("Wellington", "NZ", -41.2865, 174.7762, 0.4),
+    city("Rome", "IT", 41.9028, 12.4964, 4.3),
+    city("Milan", "IT", 45.4642, 9.1900, 3.2),
+    city("Naples", "IT", 40.8518, 14.2681, 2.2),
+    city("Athens", "GR", 37.9838, 23.7275, 3.2),
+    city("Lisbon", "PT", 38.7223, -9.1393, 2.9),
+    city("Porto", "PT", 41.1579, -8.6291, 1.3),
+    city("Manchester", "GB", 53.4808, -2.2426, 2.8),
+    city("Birmingham", "GB", 52.4862, -1.8904, 2.6),
+    city("Glasgow", "GB", 55.8642, -4.2518, 1.7),
+    city("Edinburgh", "GB", 55.9533, -3.1883, 0.5),
+    city("Dublin", "IE", 53.3498, -6.2603, 1.4),
+    city("Amsterdam", "NL", 52.3676, 4.9041, 2.5),
+    city("Rotterdam", "NL", 51.9244, 4.4777, 1.0),
+    city("Brussels", "BE", 50.8503, 4.3517, 2.1),
+    city("Hamburg", "DE", 53.5511, 9.9937, 1.9),
+    city("Munich", "DE", 48.1351, 11.5820, 1.6),
+    city("Cologne", "DE", 50.9375, 6.9603, 1.1),
+    city("Frankfurt", "DE", 50.1109, 8.6821, 0.8),
+    city("Düsseldorf", "DE", 51.2277, 6.7735, 0.6),
+    city("Stuttgart", "DE", 48.7758, 9.1829, 0.6),
+    city("Vienna", "AT", 48.2082, 16.3738, 1.9),
+    city("Zurich", "CH", 47.3769, 8.5417, 1.4),
+    city("Geneva", "CH", 46.2044, 6.1432, 0.6),
+    city("Prague", "CZ", 50.0755, 14.4378, 1.3),
+    city("Warsaw", "PL", 52.2297, 21.0122, 1.8),
+    city("Kraków", "PL", 50.0647, 19.9450, 0.8),
+    city("Budapest", "HU", 47.4979, 19.0402, 1.8),
+    city("Bucharest", "RO", 44.4268, 26.1025, 1.8),
+    city("Sofia", "BG", 42.6977, 23.3219, 1.3),
+    city("Belgrade", "RS", 44.7866, 20.4489, 1.4),
+    city("Zagreb", "HR", 45.8150, 15.9819, 0.8),
+    city("Kyiv", "UA", 50.4501, 30.5234, 3.0),
+    city("Kharkiv", "UA", 49.9935, 36.2304, 1.4),
+    city("Minsk", "BY", 53.9006, 27.5590, 2.0),
+    city("Stockholm", "SE", 59.3293, 18.0686, 1.6),
+    city("Gothenburg", "SE", 57.7089, 11.9746, 0.6),
+    city("Copenhagen", "DK", 55.6761, 12.5683, 1.4),
+    city("Oslo", "NO", 59.9139, 10.7522, 1.1),
+    city("Helsinki", "FI", 60.1699, 24.9384, 1.3),
+    city("Tallinn", "EE", 59.4370, 24.7536, 0.4),
+    city("Riga", "LV", 56.9496, 24.1052, 0.6),
+    city("Vilnius", "LT", 54.6872, 25.2797, 0.6),
+    city("Reykjavík", "IS", 64.1466, -21.9426, 0.2),
+    city("Marseille", "FR", 43.2965, 5.3698, 1.6),
+    city("Lyon", "FR", 45.7640, 4.8357, 1.7),
+    city("Toulouse", "FR", 43.6047, 1.4442, 1.0),
+    city("Valencia", "ES", 39.4699, -0.3763, 1.6),
+    city("Seville", "ES", 37.3891, -5.9845, 1.3),
+    city("Novosibirsk", "RU", 55.0084, 82.9357, 1.6),
+    city("Yekaterinburg", "RU", 56.8389, 60.6057, 1.5),
+    city("Kazan", "RU", 55.7887, 49.1221, 1.3),
+    city("Vladivostok", "RU", 43.1198, 131.8869, 0.6),
+    city("Izmir", "TR", 38.4237, 27.1428, 3.0),
+    city("Tel Aviv", "IL", 32.0853, 34.7818, 4.2),
+    city("Jerusalem", "IL", 31.7683, 35.2137, 1.0),
+    city("Amman", "JO", 31.9454, 35.9284, 4.0),
+    city("Beirut", "LB", 33.8938, 35.5018, 2.4),
+    city("Damascus", "SY", 33.5138, 36.2765, 2.5),
+    city("Dubai", "AE", 25.2048, 55.2708, 3.5),
+    city("Abu Dhabi", "AE", 24.4539, 54.3773, 1.5),
+    city("Doha", "QA", 25.2854, 51.5310, 2.4),
+    city("Manama", "BH", 26.2285, 50.5860, 0.6),
+    city("Kuwait City", "KW", 29.3759, 47.9774, 3.1),
+    city("Muscat", "OM", 23.5880, 58.3829, 1.6),
+    city("Baku", "AZ", 40.4093, 49.8671, 2.3),
+    city("Tbilisi", "GE", 41.7151, 44.8271, 1.2),
+    city("Yerevan", "AM", 40.1872, 44.5152, 1.1),
+    city("Tashkent", "UZ", 41.2995, 69.2401, 2.9),
+    city("Almaty", "KZ", 43.2220, 76.8512, 2.2),
+    city("Astana", "KZ", 51.1694, 71.4491, 1.4),
+    city("Islamabad", "PK", 33.6844, 73.0479, 1.2),
+    city("Kathmandu", "NP", 27.7172, 85.3240, 1.5),
+    city("Colombo", "LK", 6.9271, 79.8612, 0.8),
+    city("Jaipur", "IN", 26.9124, 75.7873, 4.1),
+    city("Lucknow", "IN", 26.8467, 80.9462, 3.9),
+    city("Kochi", "IN", 9.9312, 76.2673, 2.2),
+    city("Nagpur", "IN", 21.1458, 79.0882, 2.9),
+    city("Patna", "IN", 25.5941, 85.1376, 2.5),
+    city("Taipei", "TW", 25.0330, 121.5654, 7.0),
+    city("Kaohsiung", "TW", 22.6273, 120.3014, 2.7),
+    city("Busan", "KR", 35.1796, 129.0756, 3.4),
+    city("Sapporo", "JP", 43.0618, 141.3545, 2.7),
+    city("Kyoto", "JP", 35.0116, 135.7681, 1.5),
+    city("Yokohama", "JP", 35.4437, 139.6380, 3.8),
+    city("Naha", "JP", 26.2124, 127.6809, 0.3),
+    city("Pyongyang", "KP", 39.0392, 125.7625, 3.1),
+    city("Ulaanbaatar", "MN", 47.8864, 106.9057, 1.6),
+    city("Macau", "MO", 22.1987, 113.5439, 0.7),
+    city("Cebu", "PH", 10.3157, 123.8854, 3.0),
+    city("Davao", "PH", 7.1907, 125.4553, 1.8),
+    city("Surabaya", "ID", -7.2575, 112.7521, 3.0),
+    city("Bandung", "ID", -6.9175, 107.6191, 2.5),
+    city("Medan", "ID", 3.5952, 98.6722, 2.5),
+    city("Phnom Penh", "KH", 11.5564, 104.9282, 2.3),
+    city("Vientiane", "LA", 17.9757, 102.6331, 1.0),
+    city("Chiang Mai", "TH", 18.7883, 98.9853, 1.2),
+    city("Da Nang", "VN", 16.0544, 108.2022, 1.2),
+    city("Kano", "NG", 12.0022, 8.5920, 4.2),
+    city("Ibadan", "NG", 7.3775, 3.9470, 3.6),
+    city("Abuja", "NG", 9.0765, 7.3986, 3.8),
+    city("Kampala", "UG", 0.3476, 32.5825, 3.7),
+    city("Kigali", "RW", -1.9441, 30.0619, 1.2),
+    city("Dakar", "SN", 14.7167, -17.4677, 3.3),
+    city("Bamako", "ML", 12.6392, -8.0029, 2.9),
+    city("Douala", "CM", 4.0511, 9.7679, 3.9),
+    city("Yaoundé", "CM", 3.8480, 11.5021, 4.3),
+    city("Lusaka", "ZM", -15.3875, 28.3228, 3.0),
+    city("Harare", "ZW", -17.8252, 31.0335, 1.6),
+    city("Maputo", "MZ", -25.9692, 32.5732, 1.1),
+    city("Antananarivo", "MG", -18.8792, 47.5079, 3.9),
+    city("Durban", "ZA", -29.8587, 31.0218, 3.2),
+    city("Pretoria", "ZA", -25.7479, 28.2293, 2.8),
+    city("Tunis", "TN", 36.8065, 10.1815, 2.4),
+    city("Tripoli", "LY", 32.8872, 13.1913, 1.2),
+    city("Rabat", "MA", 34.0209, -6.8416, 1.9),
+    city("Mombasa", "KE", -4.0435, 39.6682, 1.4),
+    city("Montevideo", "UY", -34.9011, -56.1645, 1.8),
+    city("Asunción", "PY", -25.2637, -57.5759, 3.5),
+    city("La Paz", "BO", -16.4897, -68.1193, 1.9),
+    city("Santa Cruz de la Sierra", "BO", -17.8146, -63.1561, 1.8),
+    city("Quito", "EC", -0.1807, -78.4678, 2.0),
+    city("Guayaquil", "EC", -2.1710, -79.9224, 3.1),
+    city("Cali", "CO", 3.4516, -76.5320, 2.8),
+    city("Barranquilla", "CO", 10.9685, -74.7813, 2.3),
+    city("Córdoba", "AR", -31.4201, -64.1888, 1.6),
+    city("Rosario", "AR", -32.9442, -60.6505, 1.4),
+    city("Havana", "CU", 23.1136, -82.3666, 2.1),
+    city("Santo Domingo", "DO", 18.4861, -69.9312, 3.6),
+    city("San Juan", "PR", 18.4655, -66.1057, 2.0),
+    city("Panama City", "PA", 8.9824, -79.5199, 2.0),
+    city("San José", "CR", 9.9281, -84.0907, 1.4),
+    city("Guatemala City", "GT", 14.6349, -90.5069, 3.0),
+    city("Tijuana", "MX", 32.5149, -117.0382, 2.2),
+    city("Puebla", "MX", 19.0414, -98.2063, 3.3),
+    city("Querétaro", "MX", 20.5888, -100.3899, 1.6),
+    city("Mérida", "MX", 20.9674, -89.5926, 1.3),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_city_find() {
+        struct Test {
+            query: &'static str,
+            city: Result<(&'static str, &'static str), Error>,
+        }
+
+        let tests = vec![
+            Test {
+                query: "Lagos",
+                city: Ok(("Lagos", "NG")),
+            },
+            Test {
+                query: "São Paulo",
+                city: Ok(("São Paulo", "BR")),
+            },
+            Test {
+                query: "sao paulo",
+                city: Ok(("São Paulo", "BR")),
+            },
+            Test {
+                query: "Sao Paolo",
+                city: Ok(("São Paulo", "BR")),
+            },
+            Test {
+                query: "DUSSELDORF",
+                city: Ok(("Düsseldorf", "DE")),
+            },
+            Test {
+                query: "Bombay",
+                city: Ok(("Mumbai", "IN")),
+            },
+            Test {
+                query: "Washington, D.C.",
+                city: Ok(("Washington", "US")),
+            },
+            Test {
+                query: "Santiago, Chile",
+                city: Ok(("Santiago", "CL")),
+            },
+            Test {
+                query: "Portland, US",
+                city: Ok(("Portland", "US")),
+            },
+            Test {
+                query: "Portland, GB",
+                city: Err(Error::UnknownCity),
+            },
+            Test {
+                query: "Gotham",
+                city: Err(Error::UnknownCity),
+            },
+            Test {
+                query: " ",
+                city: Err(Error::UnknownCity),
+            },
+        ];
+
+        for t in tests {
+            let city = City::find(t.query).map(|city| (city.name(), city.country().code()));
+            assert_eq!(city, t.city, "{}", t.query);
+        }
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("Saint-Étienne"), "saintetienne");
+        assert_eq!(normalize("Kraków"), "krakow");
+        assert_eq!(normalize("St. Louis"), "stlouis");
+        assert_eq!(normalize("Straße"), "strasse");
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("paulo", "paolo"), 1);
+        assert_eq!(edit_distance("lagos", "lagos"), 0);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_find_region_for_city() {
+        let (region, city) = find_region_for_city("São Paulo", &[AwsRegion::SaEast1, AwsRegion::UsEast1]).unwrap();
+        assert_eq!(region, AwsRegion::SaEast1);
+        assert_eq!(city.name(), "São Paulo");
+
+        let (region, city) = find_region_for_city("lagos", &[AwsRegion::AfSouth1, AwsRegion::UsEast1]).unwrap();
+        assert_eq!(region, AwsRegion::AfSouth1);
+        assert_eq!(city.country().code(), "NG");
+
+        assert_eq!(
+            find_region_for_city("Gotham", &[AwsRegion::EuWest1]),
+            Err(Error::UnknownCity)
+        );
+    }
+}
@@ -0,0 +1,456 @@
+use std::str::FromStr;
+
+use crate::aws::{find_region_from_list, AwsRegion};
+use crate::latlon::{IntoLatLon, LatLon};
+use crate::Error;
+
+/// The maximum supported geohash length, which is precise to a few
+/// centimeters.
+pub const MAX_GEOHASH_PRECISION: usize = 12;
+
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// The area covered by a geohash, in degrees.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeohashCell {
+    /// The southern boundary of the cell.
+    pub south: f64,
+
+    /// The western boundary of the cell.
+    pub west: f64,
+
+    /// The northern boundary of the cell.
+    pub north: f64,
+
+    /// The eastern boundary of the cell.
+    pub east: f64,
+}
+
+impl GeohashCell {
+    /// Returns the latitude of the cell center.
+    pub fn latitude(&self) -> f64 {
+        (self.south + self.north) / 2.0
+    }
+
+    /// Returns the longitude of the cell center.
+    pub fn longitude(&self) -> f64 {
+        (self.west + self.east) / 2.0
+    }
+
+    /// Returns the location of the cell center.
+    pub fn location(&self) -> LatLon {
+        LatLon::new(self.latitude(), self.longitude())
+    }
+
+    /// Finds the nearest AWS region from a list of regions to the cell
+    /// center.
+    ///
+    /// # Panics
+    ///
+    /// Panics if regions is empty.
+    pub fn find_region_from_list(&self, regions: &[AwsRegion]) -> AwsRegion {
+        find_region_from_list(self.location(), regions)
+    }
+
+    /// Finds the AWS region from a list of regions that is the nearest one to
+    /// every location in the cell. Returns `None` if the cell is split between
+    /// several regions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if regions is empty.
+    pub fn find_unambiguous_region_from_list(&self, regions: &[AwsRegion]) -> Option<AwsRegion> {
+        let region = self.find_region_from_list(regions);
+        let nearest = unit_vector(region.location());
+
+        regions
+            .iter()
+            .filter(|other| **other != region)
+            .all(|other| {
+                let other = unit_vector(other.location());
+                // A location is nearer to the region than to the other one
+                // if it lies on the region's side of the bisecting plane
+                let normal = [nearest[0] - other[0], nearest[1] - other[1], nearest[2] - other[2]];
+                self.min_dot(normal) >= 0.0
+            })
+            .then_some(region)
+    }
+
+    /// Returns the minimum of the dot product of the given vector with all
+    /// locations in the cell, as unit vectors.
+    ///
+    /// A linear function on the sphere has its only interior extrema at the
+    /// poles of the vector, and its extrema along meridians and parallels at
+    /// closed-form angles, so checking those and the corners is exact.
+    fn min_dot(&self, normal: [f64; 3]) -> f64 {
+        let [x, y, z] = normal;
+        let (south, west, north, east) = (
+            self.south.to_radians(),
+            self.west.to_radians(),
+            self.north.to_radians(),
+            self.east.to_radians(),
+        );
+
+        let mut candidates = vec![(south, west), (south, east), (north, west), (north, east)];
+
+        // Along the western and eastern meridians
+        for lon in [west, east] {
+            let horizontal = x.mul_add(lon.cos(), y * lon.sin());
+            for lat in [z.atan2(horizontal), (-z).atan2(-horizontal)] {
+                candidates.push((lat, lon));
+            }
+        }
+
+        // Along the southern and northern parallels
+        for lat in [south, north] {
+            for lon in [y.atan2(x), (-y).atan2(-x)] {
+                candidates.push((lat, lon));
+            }
+        }
+
+        // At the antipode of the vector
+        let length = z.hypot(x.hypot(y));
+        if length > 0.0 {
+            candidates.push(((-z / length).asin(), (-y).atan2(-x)));
+        }
+
+        candidates
+            .into_iter()
+            .filter(|(lat, lon)| (south..=north).contains(lat) && (west..=east).contains(lon))
+            .map(|(lat, lon)| {
+                let [px, py, pz] = unit_vector_radians(lat, lon);
+                x.mul_add(px, y.mul_add(py, z * pz))
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+impl FromStr for GeohashCell {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        decode_geohash(s)
+    }
+}
+
+impl TryFrom<&str> for GeohashCell {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Encodes a location as a geohash of the given length, e.g. `u33dc0` for
+/// Berlin at precision 6.
+///
+/// # Panics
+///
+/// Panics if precision is zero or greater than [`MAX_GEOHASH_PRECISION`].
+pub fn encode_geohash<L: IntoLatLon>(location: L, precision: usize) -> String {
+    assert!(
+        (1..=MAX_GEOHASH_PRECISION).contains(&precision),
+        "precision must be between 1 and {MAX_GEOHASH_PRECISION}"
+    );
+
+    let location = location.into_lat_lon();
+    let (latitude, longitude) = (location.latitude(), location.longitude());
+    let (mut lat_range, mut lon_range) = ((-90.0, 90.0), (-180.0, 180.0));
+    let mut hash = String::with_capacity(precision);
+    let mut even = true;
+
+    while hash.len() < precision {
+        let mut index = 0;
+        for _ in 0..5 {
+            let (range, value) = if even {
+                (&mut lon_range, longitude)
+            } else {
+                (&mut lat_range, latitude)
+            };
+            let mid = (range.0 + range.1) / 2.0;
+            index <<= 1;
+            if value >= mid {
+                index |= 1;
+                range.0 = mid;
+            } else {
+                range.1 = mid;
+            }
+            even = !even;
+        }
+        hash.push(BASE32[index] as char);
+    }
+
+    hash
+}
+
+/// Decodes a geohash into the cell it covers. Geohashes are case-insensitive.
+pub fn decode_geohash(hash: &str) -> Result<GeohashCell, Error> {
+    if hash.is_empty() || hash.len() > MAX_GEOHASH_PRECISION {
+        return Err(Error::InvalidGeohash);
+    }
+
+    let (mut lat_range, mut lon_range) = ((-90.0, 90.0), (-180.0, 180.0));
+    let mut even = true;
+
+    for c in hash.bytes() {
+        let index = BASE32
+            .iter()
+            .position(|b| *b == c.to_ascii_lowercase())
+            .ok_or(Error::InvalidGeohash)?;
+        for bit in (0..5).rev() {
+            let range: &mut (f64, f64) = if even { &mut lon_range } else { &mut lat_range };
+            let mid = (range.0 + range.1) / 2.0;
+            if index >> bit & 1 == 1 {
+                range.0 = mid;
+            } else {
+                range.1 = mid;
+            }
+            even = !even;
+        }
+    }
+
+    Ok(GeohashCell {
+        south: lat_range.0,
+        west: lon_range.0,
+        north: lat_range.1,
+        east: lon_range.1,
+    })
+}
+
+/// Finds the nearest AWS region from a list of regions to the center of a
+/// geohash cell.
+///
+/// # Panics
+///
+/// Panics if regions is empty.
+pub fn find_region_for_geohash(hash: &str, regions: &[AwsRegion]) -> Result<AwsRegion, Error> {
+    Ok(decode_geohash(hash)?.find_region_from_list(regions))
+}
+
+/// Finds the AWS region from a list of regions that is the nearest one to
+/// every location in a geohash cell. Returns `Ok(None)` if the cell is split
+/// between several regions.
+///
+/// # Panics
+///
+/// Panics if regions is empty.
+pub fn find_unambiguous_region_for_geohash(hash: &str, regions: &[AwsRegion]) -> Result<Option<AwsRegion>, Error> {
+    Ok(decode_geohash(hash)?.find_unambiguous_region_from_list(regions))
+}
+
+/// Returns the coarsest geohash precision at which the cell containing a
+/// location maps to a single region from a list of regions.
+///
+/// This is the length of the shortest geohash that can be used as a cache key
+/// for the location.
+///
+/// Returns `None` if the location is too close to the boundary between two
+/// regions for [`MAX_GEOHASH_PRECISION`].
+///
+/// # Panics
+///
+/// Panics if regions is empty.
+pub fn find_geohash_precision<L: IntoLatLon>(location: L, regions: &[AwsRegion]) -> Option<usize> {
+    let hash = encode_geohash(location, MAX_GEOHASH_PRECISION);
+
+    (1..=MAX_GEOHASH_PRECISION).find(|precision| {
+        decode_geohash(&hash[..*precision])
+            .expect("valid geohash")
+            .find_unambiguous_region_from_list(regions)
+            .is_some()
+    })
+}
+
+fn unit_vector(location: LatLon) -> [f64; 3] {
+    unit_vector_radians(location.latitude().to_radians(), location.longitude().to_radians())
+}
+
+fn unit_vector_radians(latitude: f64, longitude: f64) -> [f64; 3] {
+    [
+        latitude.cos() * longitude.cos(),
+        latitude.cos() * longitude.sin(),
+        latitude.sin(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_encode_geohash() {
+        struct Test {
+            latitude: f64,
+            longitude: f64,
+            precision: usize,
+            hash: &'static str,
+        }
+
+        let tests = vec![
+            Test {
+                latitude: 57.64911,
+                longitude: 10.40744,
+                precision: 11,
+                hash: "u4pruydqqvj",
+            },
+            Test {
+                latitude: 52.5200,
+                longitude: 13.4050,
+                precision: 6,
+                hash: "u33dc0",
+            },
+            Test {
+                latitude: -33.8688,
+                longitude: 151.2093,
+                precision: 5,
+                hash: "r3gx2",
+            },
+            Test {
+                latitude: 0.0,
+                longitude: 0.0,
+                precision: 1,
+                hash: "s",
+            },
+        ];
+
+        for t in tests {
+            assert_eq!(encode_geohash((t.latitude, t.longitude), t.precision), t.hash);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "precision must be between 1 and 12")]
+    fn test_encode_geohash_invalid_precision() {
+        encode_geohash((0.0, 0.0), 13);
+    }
+
+    #[test]
+    fn test_decode_geohash() {
+        let cell = decode_geohash("ezs42").unwrap();
+        assert!((cell.latitude() - 42.605).abs() < 0.001);
+        assert!((cell.longitude() - -5.603).abs() < 0.001);
+
+        assert_eq!(decode_geohash("S"), "s".parse());
+        assert_eq!(
+            decode_geohash("s"),
+            Ok(GeohashCell {
+                south: 0.0,
+                west: 0.0,
+                north: 45.0,
+                east: 45.0,
+            })
+        );
+
+        for hash in ["", "u4pa", "u4pruydqqvjxx", "ü"] {
+            assert_eq!(decode_geohash(hash), Err(Error::InvalidGeohash), "{hash}");
+        }
+
+        let hash = encode_geohash((40.7128, -74.0060), MAX_GEOHASH_PRECISION);
+        let cell = decode_geohash(&hash).unwrap();
+        assert!((cell.latitude() - 40.7128).abs() < 1e-6);
+        assert!((cell.longitude() - -74.0060).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_find_region_for_geohash() {
+        let list = [AwsRegion::EuCentral1, AwsRegion::UsEast1];
+
+        assert_eq!(find_region_for_geohash("u33dc0", &list), Ok(AwsRegion::EuCentral1));
+        assert_eq!(find_region_for_geohash("dr5reg", &list), Ok(AwsRegion::UsEast1));
+        assert_eq!(find_region_for_geohash("", &list), Err(Error::InvalidGeohash));
+    }
+
+    #[test]
+    fn test_find_unambiguous_region_for_geohash() {
+        struct Test {
+            hash: &'static str,
+            list: Vec<AwsRegion>,
+            region: Option<AwsRegion>,
+        }
+
+        let tests = vec![
+            Test {
+                hash: "u",
+                list: vec![AwsRegion::EuCentral1, AwsRegion::UsEast1],
+                region: Some(AwsRegion::EuCentral1),
+            },
+            Test {
+                hash: "u",
+                list: vec![AwsRegion::EuCentral1, AwsRegion::EuNorth1],
+                region: None,
+            },
+            Test {
+                hash: "u33",
+                list: vec![AwsRegion::EuCentral1, AwsRegion::EuNorth1, AwsRegion::EuWest1],
+                region: Some(AwsRegion::EuCentral1),
+            },
+            Test {
+                hash: "gcp",
+                list: vec![AwsRegion::EuWest2, AwsRegion::EuWest2],
+                region: Some(AwsRegion::EuWest2),
+            },
+        ];
+
+        for t in tests {
+            let region = find_unambiguous_region_for_geohash(t.hash, &t.list);
+            assert_eq!(region, Ok(t.region), "{}", t.hash);
+        }
+    }
+
+    #[test]
+    fn test_find_unambiguous_region_matches_sampling() {
+        let list = [
+            AwsRegion::EuWest1,
+            AwsRegion::EuWest2,
+            AwsRegion::EuCentral1,
+            AwsRegion::EuNorth1,
+        ];
+
+        for first in ["g", "u"] {
+            for c in BASE32.iter().map(|b| *b as char) {
+                let cell = decode_geohash(&format!("{first}{c}")).unwrap();
+                let steps = 20;
+                let sampled: Vec<AwsRegion> = (0..=steps)
+                    .flat_map(|i| (0..=steps).map(move |j| (i, j)))
+                    .map(|(i, j)| {
+                        let latitude = (cell.north - cell.south).mul_add(f64::from(i) / f64::from(steps), cell.south);
+                        let longitude = (cell.east - cell.west).mul_add(f64::from(j) / f64::from(steps), cell.west);
+                        find_region_from_list((latitude, longitude), &list)
+                    })
+                    .collect();
+
+                // Sampling can miss a sliver of another region, but never
+                // finds more than one region in an unambiguous cell
+                if let Some(region) = cell.find_unambiguous_region_from_list(&list) {
+                    assert!(sampled.iter().all(|r| *r == region), "{first}{c}");
+                } else {
+                    assert!(sampled.windows(2).any(|w| w[0] != w[1]), "{first}{c}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_geohash_precision() {
+        let list = [AwsRegion::EuWest1, AwsRegion::EuWest2, AwsRegion::EuCentral1];
+
+        // Berlin is far from any boundary, Brussels is close to the one
+        // between London and Frankfurt
+        let berlin = find_geohash_precision((52.5200, 13.4050), &list).unwrap();
+        let brussels = find_geohash_precision((50.8503, 4.3517), &list).unwrap();
+        assert!(berlin < brussels, "{berlin} {brussels}");
+
+        for (latitude, longitude, precision) in [(52.5200, 13.4050, berlin), (50.8503, 4.3517, brussels)] {
+            let hash = encode_geohash((latitude, longitude), precision);
+            assert!(find_unambiguous_region_for_geohash(&hash, &list).unwrap().is_some());
+            if precision > 1 {
+                let hash = &hash[..precision - 1];
+                assert_eq!(find_unambiguous_region_for_geohash(hash, &list), Ok(None));
+            }
+        }
+
+        assert_eq!(find_geohash_precision((0.0, 0.0), &[AwsRegion::UsEast1]), Some(1));
+    }
+}
@@ -0,0 +1,208 @@
+use crate::aws::AwsRegion;
+use crate::latlon::{IntoLatLon, LatLon};
+use crate::stats::weighted_percentile;
+
+/// What to minimize when choosing a region for a group of locations.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GroupObjective {
+    /// The maximum distance of any location.
+    Max,
+
+    /// The (weighted) mean distance.
+    Mean,
+
+    /// The (weighted) 95th percentile distance.
+    P95,
+}
+
+impl GroupObjective {
+    /// Returns the score of the given distances and weights.
+    ///
+    /// Locations with a weight of zero or less are ignored.
+    pub(crate) fn score(&self, distances: &[f64], weights: &[f64]) -> f64 {
+        match *self {
+            Self::Max => distances
+                .iter()
+                .zip(weights)
+                .filter(|(_, w)| **w > 0.0)
+                .map(|(d, _)| *d)
+                .fold(0.0, f64::max),
+            Self::Mean => {
+                let total: f64 = weights.iter().filter(|w| **w > 0.0).sum();
+                let sum: f64 = distances
+                    .iter()
+                    .zip(weights)
+                    .filter(|(_, w)| **w > 0.0)
+                    .map(|(d, w)| d * w)
+                    .sum();
+                if total > 0.0 {
+                    sum / total
+                } else {
+                    0.0
+                }
+            }
+            Self::P95 => weighted_percentile(distances, weights, 95.0),
+        }
+    }
+}
+
+/// The result of a group region search.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GroupRegion {
+    /// The region that serves the group best.
+    pub region: AwsRegion,
+
+    /// The value of the objective for the chosen region, in meters.
+    pub score: f64,
+
+    /// The distance in meters from each location to the chosen region, in
+    /// the order the locations were given.
+    pub distances: Vec<f64>,
+}
+
+/// Finds the AWS region from a list of regions that serves a group of
+/// locations best.
+///
+/// # Panics
+///
+/// Panics if regions or locations is empty.
+pub fn find_group_region_from_list<L: IntoLatLon + Copy>(
+    locations: &[L],
+    regions: &[AwsRegion],
+    objective: GroupObjective,
+) -> GroupRegion {
+    let weighted: Vec<(LatLon, f64)> = locations
+        .iter()
+        .map(|location| (location.into_lat_lon(), 1.0))
+        .collect();
+
+    find_weighted_group_region_from_list(&weighted, regions, objective)
+}
+
+/// Finds the AWS region from a list of regions that serves a group of
+/// `(location, weight)` pairs best. Locations with a weight of zero or less
+/// are ignored.
+///
+/// # Panics
+///
+/// Panics if regions or locations is empty, or no weight is positive.
+pub fn find_weighted_group_region_from_list<L: IntoLatLon + Copy>(
+    locations: &[(L, f64)],
+    regions: &[AwsRegion],
+    objective: GroupObjective,
+) -> GroupRegion {
+    assert!(!locations.is_empty(), "locations must not be empty");
+    assert!(
+        locations.iter().any(|&(_, weight)| weight > 0.0),
+        "at least one weight must be positive"
+    );
+
+    let points: Vec<LatLon> = locations.iter().map(|(location, _)| location.into_lat_lon()).collect();
+    let weights: Vec<f64> = locations.iter().map(|&(_, weight)| weight).collect();
+
+    regions
+        .iter()
+        .map(|region| {
+            let distances: Vec<f64> = points.iter().map(|point| region.distance_to(point)).collect();
+            let score = objective.score(&distances, &weights);
+            GroupRegion {
+                region: *region,
+                score,
+                distances,
+            }
+        })
+        .min_by_key(|group| ordered_float::OrderedFloat(group.score))
+        .expect("regions must not be empty")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const HAMBURG: (f64, f64) = (53.5511, 9.9937);
+    const MANCHESTER: (f64, f64) = (53.4808, -2.2426);
+    const CAIRO: (f64, f64) = (30.0444, 31.2357);
+    const BOSTON: (f64, f64) = (42.3601, -71.0589);
+
+    #[test]
+    fn test_find_group_region_from_list() {
+        struct Test {
+            objective: GroupObjective,
+            locations: Vec<(f64, f64)>,
+            list: Vec<AwsRegion>,
+            region: AwsRegion,
+        }
+
+        let tests = vec![
+            Test {
+                objective: GroupObjective::Mean,
+                locations: vec![HAMBURG, MANCHESTER, HAMBURG],
+                list: vec![AwsRegion::EuWest1, AwsRegion::EuCentral1, AwsRegion::UsEast1],
+                region: AwsRegion::EuCentral1,
+            },
+            Test {
+                objective: GroupObjective::Max,
+                locations: vec![HAMBURG, BOSTON],
+                list: vec![AwsRegion::EuCentral1, AwsRegion::UsEast1, AwsRegion::EuWest1],
+                region: AwsRegion::EuWest1,
+            },
+            Test {
+                objective: GroupObjective::P95,
+                locations: vec![MANCHESTER, CAIRO],
+                list: vec![AwsRegion::EuWest2, AwsRegion::MeSouth1, AwsRegion::EuSouth1],
+                region: AwsRegion::EuSouth1,
+            },
+        ];
+
+        for t in tests {
+            let group = find_group_region_from_list(&t.locations, &t.list, t.objective);
+            assert_eq!(group.region, t.region, "{:?}", t.objective);
+            assert_eq!(group.distances.len(), t.locations.len(), "{:?}", t.objective);
+        }
+    }
+
+    #[test]
+    fn test_find_weighted_group_region_from_list() {
+        let list = [AwsRegion::EuWest2, AwsRegion::UsEast1];
+
+        let group =
+            find_weighted_group_region_from_list(&[(MANCHESTER, 1.0), (BOSTON, 5.0)], &list, GroupObjective::Mean);
+        assert_eq!(group.region, AwsRegion::UsEast1);
+        assert_eq!(
+            group.distances,
+            vec![
+                AwsRegion::UsEast1.distance_to(MANCHESTER),
+                AwsRegion::UsEast1.distance_to(BOSTON),
+            ]
+        );
+
+        let group =
+            find_weighted_group_region_from_list(&[(MANCHESTER, 5.0), (BOSTON, 1.0)], &list, GroupObjective::Mean);
+        assert_eq!(group.region, AwsRegion::EuWest2);
+    }
+
+    #[test]
+    #[should_panic(expected = "regions must not be empty")]
+    fn test_find_group_region_from_empty_list() {
+        find_group_region_from_list(&[HAMBURG], &[], GroupObjective::Mean);
+    }
+
+    #[test]
+    #[should_panic(expected = "locations must not be empty")]
+    fn test_find_group_region_without_locations() {
+        find_group_region_from_list::<LatLon>(&[], &[AwsRegion::EuWest1], GroupObjective::Mean);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one weight must be positive")]
+    fn test_find_weighted_group_region_without_positive_weights() {
+        find_weighted_group_region_from_list(
+            &[(HAMBURG, 0.0), (BOSTON, -1.0)],
+            &[AwsRegion::EuWest1],
+            GroupObjective::Mean,
+        );
+    }
+}
@@ -0,0 +1,554 @@
+use std::net::IpAddr;
+
+use crate::airport::Airport;
+use crate::aws::AwsRegion;
+use crate::country::find_region_for_country;
+#[cfg(feature = "maxmind")]
+use crate::maxmind::GeoIpDatabase;
+use crate::subdivision::find_region_for_subdivision;
+
+/// How much a location hint can be trusted.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Confidence {
+    /// Only the country, the subdivision or the client IP is known.
+    Low,
+
+    /// The location of the edge POP or region serving the request is known.
+    Medium,
+
+    /// The geolocated coordinates of the client are known.
+    High,
+}
+
+/// The kind of location found in request headers.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HintKind {
+    /// Coordinates of the client or the edge serving it.
+    Coordinates {
+        /// The latitude.
+        latitude: f64,
+        /// The longitude.
+        longitude: f64,
+    },
+
+    /// An ISO 3166-2 subdivision code, e.g. `US-CA`, in upper case.
+    Subdivision(String),
+
+    /// An ISO 3166-1 alpha-2 country code, in upper case.
+    Country(String),
+
+    /// The IP address of the client, which can be geolocated separately.
+    IpAddress(IpAddr),
+}
+
+/// A client location hint extracted from request headers.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LocationHint {
+    /// The location found.
+    pub kind: HintKind,
+
+    /// How much the location can be trusted.
+    pub confidence: Confidence,
+
+    /// The (lower case) header the location was taken from.
+    pub header: &'static str,
+}
+
+impl LocationHint {
+    /// Finds the nearest AWS region from a list of regions to the hinted
+    /// location. Subdivisions and countries are resolved with
+    /// [`find_region_for_subdivision`] and [`find_region_for_country`].
+    /// Returns `None` if the hint cannot be resolved.
+    ///
+    /// IP address hints are informational and always return `None`. With the
+    /// `maxmind` feature, use `find_region_from_list_with_geoip` to resolve
+    /// them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if regions is empty.
+    pub fn find_region_from_list(&self, regions: &[AwsRegion]) -> Option<AwsRegion> {
+        match self.kind {
+            HintKind::Coordinates { latitude, longitude } => {
+                Some(crate::find_region_from_list((latitude, longitude), regions))
+            }
+            HintKind::Subdivision(ref code) => find_region_for_subdivision(code, regions).ok(),
+            HintKind::Country(ref code) => find_region_for_country(code, regions).ok(),
+            HintKind::IpAddress(_) => None,
+        }
+    }
+
+    /// Like [`LocationHint::find_region_from_list`], but also resolves IP
+    /// address hints with the given database.
+    ///
+    /// # Panics
+    ///
+    /// Panics if regions is empty.
+    #[cfg(feature = "maxmind")]
+    pub fn find_region_from_list_with_geoip(
+        &self,
+        regions: &[AwsRegion],
+        database: &GeoIpDatabase,
+    ) -> Option<AwsRegion> {
+        match self.kind {
+            HintKind::IpAddress(ip) => database.find_region_from_list(ip, regions).ok(),
+            _ => self.find_region_from_list(regions),
+        }
+    }
+}
+
+/// Headers carrying latitude and longitude, in order of preference.
+const COORDINATE_HEADERS: [(&str, &str); 3] = [
+    ("cloudfront-viewer-latitude", "cloudfront-viewer-longitude"),
+    ("x-vercel-ip-latitude", "x-vercel-ip-longitude"),
+    ("cf-iplatitude", "cf-iplongitude"),
+];
+
+/// Headers carrying a subdivision code without the country, together with the
+/// header carrying the country, in order of preference.
+const SUBDIVISION_HEADERS: [(&str, &str); 3] = [
+    ("cloudfront-viewer-country", "cloudfront-viewer-country-region"),
+    ("x-vercel-ip-country", "x-vercel-ip-country-region"),
+    ("cf-ipcountry", "cf-region-code"),
+];
+
+/// Headers carrying a country code, in order of preference.
+const COUNTRY_HEADERS: [&str; 3] = ["cloudfront-viewer-country", "x-vercel-ip-country", "cf-ipcountry"];
+
+/// Headers carrying the client IP, in order of preference.
+const IP_HEADERS: [&str; 6] = [
+    "cf-connecting-ip",
+    "fly-client-ip",
+    "true-client-ip",
+    "x-real-ip",
+    "x-vercel-forwarded-for",
+    "x-forwarded-for",
+];
+
+/// Headers carrying the edge POP serving the request, in order of preference.
+/// Fly.io regions and CloudFront POPs start with an IATA airport code.
+const POP_HEADERS: [&str; 2] = ["fly-region", "x-amz-cf-pop"];
+
+/// Extracts all client location hints from request headers, best first.
+///
+/// Header names are matched case-insensitively. Values that cannot be parsed
+/// are ignored.
+pub fn location_hints<I, K, V>(headers: I) -> Vec<LocationHint>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    let headers: Vec<(String, String)> = headers
+        .into_iter()
+        .map(|(name, value)| (name.as_ref().to_ascii_lowercase(), value.as_ref().trim().to_owned()))
+        .collect();
+    let get = |name: &str| {
+        headers
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value.as_str())
+            .filter(|value| !value.is_empty())
+    };
+
+    let mut hints = Vec::new();
+
+    for (lat_header, lon_header) in COORDINATE_HEADERS {
+        let latitude = get(lat_header).and_then(|v| v.parse::<f64>().ok());
+        let longitude = get(lon_header).and_then(|v| v.parse::<f64>().ok());
+        if let (Some(latitude), Some(longitude)) = (latitude, longitude) {
+            if (-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude) {
+                hints.push(LocationHint {
+                    kind: HintKind::Coordinates { latitude, longitude },
+                    confidence: Confidence::High,
+                    header: lat_header,
+                });
+            }
+        }
+    }
+
+    for header in POP_HEADERS {
+        if let Some(airport) = get(header).and_then(|pop| Airport::from_pop(pop).ok()) {
+            let location = airport.location();
+            hints.push(LocationHint {
+                kind: HintKind::Coordinates {
+                    latitude: location.latitude(),
+                    longitude: location.longitude(),
+                },
+                confidence: Confidence::Medium,
+                header,
+            });
+        }
+    }
+
+    // Cloudflare appends the colo to the ray ID, e.g. 7d8e9a0b1c2d3e4f-FRA
+    if let Some(airport) = get("cf-ray")
+        .and_then(|ray| ray.rsplit_once('-'))
+        .and_then(|(_, colo)| colo.parse::<Airport>().ok())
+    {
+        let location = airport.location();
+        hints.push(LocationHint {
+            kind: HintKind::Coordinates {
+                latitude: location.latitude(),
+                longitude: location.longitude(),
+            },
+            confidence: Confidence::Medium,
+            header: "cf-ray",
+        });
+    }
+
+    for (country_header, header) in SUBDIVISION_HEADERS {
+        let country = get(country_header).filter(|c| is_country_code(c));
+        let code = get(header).filter(|c| c.len() <= 3 && c.bytes().all(|b| b.is_ascii_alphanumeric()));
+        if let (Some(country), Some(code)) = (country, code) {
+            hints.push(LocationHint {
+                kind: HintKind::Subdivision(format!("{country}-{code}").to_ascii_uppercase()),
+                confidence: Confidence::Low,
+                header,
+            });
+        }
+    }
+
+    for header in COUNTRY_HEADERS {
+        if let Some(code) = get(header).filter(|c| is_country_code(c)) {
+            hints.push(LocationHint {
+                kind: HintKind::Country(code.to_ascii_uppercase()),
+                confidence: Confidence::Low,
+                header,
+            });
+        }
+    }
+
+    for header in IP_HEADERS {
+        // X-Forwarded-For lists the client first, followed by proxies
+        let ip = get(header)
+            .and_then(|v| v.split(',').next())
+            .and_then(|v| v.trim().parse().ok());
+        if let Some(ip) = ip {
+            hints.push(LocationHint {
+                kind: HintKind::IpAddress(ip),
+                confidence: Confidence::Low,
+                header,
+            });
+        }
+    }
+
+    // Stable sort keeps the header preference within each confidence level
+    hints.sort_by_key(|hint| std::cmp::Reverse(hint.confidence));
+    hints
+}
+
+/// Deserializes a hint, mapping the header back to one location hints are
+/// taken from.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LocationHint {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{Error, Unexpected};
+
+        #[derive(serde::Deserialize)]
+        struct Hint {
+            kind: HintKind,
+            confidence: Confidence,
+            header: String,
+        }
+
+        let hint = Hint::deserialize(deserializer)?;
+        let header = COORDINATE_HEADERS
+            .iter()
+            .map(|(header, _)| *header)
+            .chain(POP_HEADERS)
+            .chain(["cf-ray"])
+            .chain(SUBDIVISION_HEADERS.iter().map(|(_, header)| *header))
+            .chain(COUNTRY_HEADERS)
+            .chain(IP_HEADERS)
+            .find(|known| known.eq_ignore_ascii_case(&hint.header))
+            .ok_or_else(|| D::Error::invalid_value(Unexpected::Str(&hint.header), &"a location header"))?;
+
+        Ok(Self {
+            kind: hint.kind,
+            confidence: hint.confidence,
+            header,
+        })
+    }
+}
+
+fn is_country_code(code: &str) -> bool {
+    // Cloudflare uses XX for unknown and T1 for Tor
+    code.len() == 2 && code.bytes().all(|b| b.is_ascii_alphabetic()) && !code.eq_ignore_ascii_case("XX")
+}
+
+/// Extracts the best client location hint from request headers.
+pub fn best_location_hint<I, K, V>(headers: I) -> Option<LocationHint>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    location_hints(headers).into_iter().next()
+}
+
+/// Finds the nearest AWS region from a list of regions to the client, using the
+/// best location hint in the request headers that can be resolved.
+///
+/// Returns the region together with the hint used, or `None` if no hint could
+/// be resolved. IP address hints are skipped, as they need a geolocation
+/// database, see `find_region_from_headers_with_geoip`.
+///
+/// # Panics
+///
+/// Panics if regions is empty.
+pub fn find_region_from_headers<I, K, V>(headers: I, regions: &[AwsRegion]) -> Option<(AwsRegion, LocationHint)>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    location_hints(headers)
+        .into_iter()
+        .filter(|hint| !matches!(hint.kind, HintKind::IpAddress(_)))
+        .find_map(|hint| hint.find_region_from_list(regions).map(|region| (region, hint)))
+}
+
+/// Like [`find_region_from_headers`], but resolves IP address hints with the
+/// given database if no better hint can be resolved.
+///
+/// # Panics
+///
+/// Panics if regions is empty.
+#[cfg(feature = "maxmind")]
+pub fn find_region_from_headers_with_geoip<I, K, V>(
+    headers: I,
+    regions: &[AwsRegion],
+    database: &GeoIpDatabase,
+) -> Option<(AwsRegion, LocationHint)>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    location_hints(headers).into_iter().find_map(|hint| {
+        hint.find_region_from_list_with_geoip(regions, database)
+            .map(|region| (region, hint))
+    })
+}
+
+/// Extracts all client location hints from an [`http::HeaderMap`], best first.
+#[cfg(feature = "http")]
+pub fn location_hints_from_header_map(headers: &http::HeaderMap) -> Vec<LocationHint> {
+    location_hints(header_pairs(headers))
+}
+
+/// Like [`find_region_from_headers`], but takes an [`http::HeaderMap`].
+///
+/// # Panics
+///
+/// Panics if regions is empty.
+#[cfg(feature = "http")]
+pub fn find_region_from_header_map(
+    headers: &http::HeaderMap,
+    regions: &[AwsRegion],
+) -> Option<(AwsRegion, LocationHint)> {
+    find_region_from_headers(header_pairs(headers), regions)
+}
+
+/// Like [`find_region_from_headers_with_geoip`], but takes an
+/// [`http::HeaderMap`].
+///
+/// # Panics
+///
+/// Panics if regions is empty.
+#[cfg(all(feature = "http", feature = "maxmind"))]
+pub fn find_region_from_header_map_with_geoip(
+    headers: &http::HeaderMap,
+    regions: &[AwsRegion],
+    database: &GeoIpDatabase,
+) -> Option<(AwsRegion, LocationHint)> {
+    find_region_from_headers_with_geoip(header_pairs(headers), regions, database)
+}
+
+#[cfg(feature = "http")]
+fn header_pairs(headers: &http::HeaderMap) -> impl Iterator<Item = (&str, &str)> {
+    headers
+        .iter()
+        .filter_map(|(name, value)| value.to_str().ok().map(|value| (name.as_str(), value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_location_hints() {
+        let hints = location_hints([
+            ("X-Forwarded-For", "203.0.113.7, 10.0.0.1"),
+            ("CF-IPCountry", "de"),
+            ("CF-Region-Code", "by"),
+            ("Fly-Region", "fra"),
+            ("CloudFront-Viewer-Latitude", "53.5511"),
+            ("CloudFront-Viewer-Longitude", "9.9937"),
+        ]);
+
+        assert_eq!(
+            hints,
+            vec![
+                LocationHint {
+                    kind: HintKind::Coordinates {
+                        latitude: 53.5511,
+                        longitude: 9.9937,
+                    },
+                    confidence: Confidence::High,
+                    header: "cloudfront-viewer-latitude",
+                },
+                LocationHint {
+                    kind: HintKind::Coordinates {
+                        latitude: 50.0379,
+                        longitude: 8.5622,
+                    },
+                    confidence: Confidence::Medium,
+                    header: "fly-region",
+                },
+                LocationHint {
+                    kind: HintKind::Subdivision("DE-BY".to_string()),
+                    confidence: Confidence::Low,
+                    header: "cf-region-code",
+                },
+                LocationHint {
+                    kind: HintKind::Country("DE".to_string()),
+                    confidence: Confidence::Low,
+                    header: "cf-ipcountry",
+                },
+                LocationHint {
+                    kind: HintKind::IpAddress("203.0.113.7".parse().unwrap()),
+                    confidence: Confidence::Low,
+                    header: "x-forwarded-for",
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_location_hints_ignores_invalid_values() {
+        let hints = location_hints([
+            ("x-vercel-ip-latitude", "not-a-number"),
+            ("x-vercel-ip-longitude", "9.9937"),
+            ("cf-iplatitude", "123.0"),
+            ("cf-iplongitude", "9.9937"),
+            ("cf-ipcountry", "XX"),
+            ("cf-region-code", "BY"),
+            ("fly-region", "xyz"),
+            ("x-amz-cf-pop", "XYZ1-C1"),
+            ("cf-ray", "7d8e9a0b1c2d3e4f"),
+            ("x-real-ip", "unknown"),
+        ]);
+
+        assert_eq!(hints, vec![]);
+    }
+
+    #[test]
+    fn test_best_location_hint() {
+        let hint = best_location_hint(vec![("cf-ipcountry".to_string(), "GB".to_string())]).unwrap();
+        assert_eq!(hint.kind, HintKind::Country("GB".to_string()));
+        assert_eq!(hint.confidence, Confidence::Low);
+
+        assert_eq!(best_location_hint(Vec::<(&str, &str)>::new()), None);
+    }
+
+    #[test]
+    fn test_find_region_from_headers() {
+        struct Test {
+            headers: Vec<(&'static str, &'static str)>,
+            list: Vec<AwsRegion>,
+            region: Option<AwsRegion>,
+        }
+
+        let tests = vec![
+            Test {
+                headers: vec![
+                    ("x-vercel-ip-latitude", "53.4808"),
+                    ("x-vercel-ip-longitude", "-2.2426"),
+                ],
+                list: vec![AwsRegion::EuWest1, AwsRegion::EuWest2],
+                region: Some(AwsRegion::EuWest2),
+            },
+            Test {
+                headers: vec![("x-amz-cf-pop", "HIO50-C1"), ("cloudfront-viewer-country", "US")],
+                list: vec![AwsRegion::UsEast1, AwsRegion::UsWest2],
+                region: Some(AwsRegion::UsWest2),
+            },
+            Test {
+                headers: vec![("cf-ray", "7d8e9a0b1c2d3e4f-SIN"), ("cf-ipcountry", "AU")],
+                list: vec![AwsRegion::ApSoutheast1, AwsRegion::ApSoutheast2],
+                region: Some(AwsRegion::ApSoutheast1),
+            },
+            Test {
+                headers: vec![("fly-region", "nrt"), ("cf-ipcountry", "US")],
+                list: vec![AwsRegion::UsWest2, AwsRegion::ApNortheast1],
+                region: Some(AwsRegion::ApNortheast1),
+            },
+            Test {
+                headers: vec![
+                    ("cloudfront-viewer-country", "US"),
+                    ("cloudfront-viewer-country-region", "WA"),
+                ],
+                list: vec![AwsRegion::UsEast1, AwsRegion::UsWest2],
+                region: Some(AwsRegion::UsWest2),
+            },
+            Test {
+                headers: vec![("cf-ipcountry", "AU"), ("x-forwarded-for", "203.0.113.7")],
+                list: vec![AwsRegion::ApSoutheast1, AwsRegion::ApSoutheast2],
+                region: Some(AwsRegion::ApSoutheast2),
+            },
+            Test {
+                headers: vec![("cf-ipcountry", "T1"), ("x-forwarded-for", "203.0.113.7")],
+                list: vec![AwsRegion::UsWest2],
+                region: None,
+            },
+        ];
+
+        for t in tests {
+            let region = find_region_from_headers(t.headers.iter().copied(), &t.list).map(|(region, _)| region);
+            assert_eq!(region, t.region, "{:?}", t.headers);
+        }
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn test_find_region_from_header_map() {
+        let mut headers = http::HeaderMap::new();
+        headers.insert("cloudfront-viewer-latitude", "35.0116".parse().unwrap());
+        headers.insert("cloudfront-viewer-longitude", "135.7681".parse().unwrap());
+
+        let (region, hint) =
+            find_region_from_header_map(&headers, &[AwsRegion::ApNortheast1, AwsRegion::ApNortheast3]).unwrap();
+        assert_eq!(region, AwsRegion::ApNortheast3);
+        assert_eq!(hint.confidence, Confidence::High);
+        assert_eq!(location_hints_from_header_map(&headers), vec![hint]);
+    }
+
+    #[cfg(feature = "maxmind")]
+    #[test]
+    fn test_find_region_from_headers_with_geoip() {
+        let database =
+            GeoIpDatabase::open(concat!(env!("CARGO_MANIFEST_DIR"), "/testdata/GeoLite2-City-Test.mmdb")).unwrap();
+        let regions = [AwsRegion::UsEast1, AwsRegion::EuWest2, AwsRegion::EuNorth1];
+
+        let headers = [("x-forwarded-for", "89.160.20.112, 10.0.0.1")];
+        assert_eq!(find_region_from_headers(headers, &regions), None);
+
+        let (region, hint) = find_region_from_headers_with_geoip(headers, &regions, &database).unwrap();
+        assert_eq!(region, AwsRegion::EuNorth1);
+        assert_eq!(hint.kind, HintKind::IpAddress("89.160.20.112".parse().unwrap()));
+
+        // Better hints take precedence over the IP address
+        let headers = [("x-forwarded-for", "89.160.20.112"), ("cf-ipcountry", "GB")];
+        let (region, _) = find_region_from_headers_with_geoip(headers, &regions, &database).unwrap();
+        assert_eq!(region, AwsRegion::EuWest2);
+
+        let headers = [("x-forwarded-for", "10.0.0.1")];
+        assert_eq!(find_region_from_headers_with_geoip(headers, &regions, &database), None);
+    }
+}
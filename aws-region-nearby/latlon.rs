@@ -0,0 +1,266 @@
+/// Mean radius of the Earth in meters, as used for all distances.
+pub const EARTH_RADIUS: f64 = 6_371_000.0;
+
+/// A location on Earth, given by its latitude and longitude in decimal
+/// degrees.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LatLon {
+    latitude: f64,
+    longitude: f64,
+}
+
+impl LatLon {
+    /// Creates a location from its latitude and longitude.
+    pub const fn new(latitude: f64, longitude: f64) -> Self {
+        Self { latitude, longitude }
+    }
+
+    /// Returns the latitude in decimal degrees.
+    pub const fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// Returns the longitude in decimal degrees.
+    pub const fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// Returns the great-circle distance in meters to the given location,
+    /// rounded to the millimeter.
+    ///
+    /// The math comes from `libm`, so that distances are the same with and
+    /// without `std` and on every target.
+    pub fn distance_to<L: IntoLatLon>(&self, to: L) -> f64 {
+        let to = to.into_lat_lon();
+        let haversine = |theta: f64| (1.0 - libm::cos(theta)) / 2.0;
+
+        let (phi1, phi2) = (self.latitude.to_radians(), to.latitude.to_radians());
+        let (lambda1, lambda2) = (self.longitude.to_radians(), to.longitude.to_radians());
+        let h = libm::fma(
+            libm::cos(phi1) * libm::cos(phi2),
+            haversine(lambda2 - lambda1),
+            haversine(phi2 - phi1),
+        );
+        let distance = 2.0 * EARTH_RADIUS * libm::asin(libm::sqrt(h));
+
+        libm::round(distance * 1000.0) / 1000.0
+    }
+
+    /// Returns the haversine of the central angle to the given location, which
+    /// grows with the distance. Unlike [`distance_to`](Self::distance_to), this
+    /// can be evaluated at compile time.
+    pub(crate) const fn const_haversine(&self, to: Self) -> f64 {
+        let half_phi = (to.latitude - self.latitude).to_radians() / 2.0;
+        let half_lambda = normalize_degrees(to.longitude - self.longitude).to_radians() / 2.0;
+        let (a, b) = (const_sin(half_phi), const_sin(half_lambda));
+        let cos_phis = const_cos(self.latitude.to_radians()) * const_cos(to.latitude.to_radians());
+        a * a + cos_phis * b * b
+    }
+}
+
+/// Maps an angle in degrees into `[-180, 180]`.
+const fn normalize_degrees(degrees: f64) -> f64 {
+    let degrees = degrees % 360.0;
+    if degrees > 180.0 {
+        degrees - 360.0
+    } else if degrees < -180.0 {
+        degrees + 360.0
+    } else {
+        degrees
+    }
+}
+
+/// Returns the sine of an angle in `[-π, π]` from its Taylor series, as
+/// `f64::sin` is not const.
+const fn const_sin(x: f64) -> f64 {
+    let (mut term, mut sum) = (x, x);
+    let mut n = 1;
+    while n < 27 {
+        term *= -x * x / ((n + 1) * (n + 2)) as f64;
+        sum += term;
+        n += 2;
+    }
+    sum
+}
+
+/// Returns the cosine of an angle in `[-π/2, π/2]`.
+const fn const_cos(x: f64) -> f64 {
+    const_sin(core::f64::consts::FRAC_PI_2 - x)
+}
+
+/// Conversion into a [`LatLon`], accepted by all functions that take a
+/// location.
+///
+/// Implemented for `(latitude, longitude)` tuples, the location types of this
+/// crate and, behind the `geo-types` and `geoutils` features, the point types
+/// of those crates.
+pub trait IntoLatLon {
+    /// Converts the value into a [`LatLon`].
+    fn into_lat_lon(self) -> LatLon;
+}
+
+impl IntoLatLon for LatLon {
+    fn into_lat_lon(self) -> LatLon {
+        self
+    }
+}
+
+impl<L: IntoLatLon + Copy> IntoLatLon for &L {
+    fn into_lat_lon(self) -> LatLon {
+        (*self).into_lat_lon()
+    }
+}
+
+impl<T: Into<f64>> IntoLatLon for (T, T) {
+    fn into_lat_lon(self) -> LatLon {
+        LatLon::new(self.0.into(), self.1.into())
+    }
+}
+
+impl<T: Into<f64>> From<(T, T)> for LatLon {
+    fn from((latitude, longitude): (T, T)) -> Self {
+        Self::new(latitude.into(), longitude.into())
+    }
+}
+
+impl From<LatLon> for (f64, f64) {
+    fn from(location: LatLon) -> Self {
+        (location.latitude, location.longitude)
+    }
+}
+
+// geo re-exports these types, so this also covers geo::Point and geo::Coord
+#[cfg(feature = "geo-types")]
+mod geo_types_impls {
+    use super::{IntoLatLon, LatLon};
+
+    impl IntoLatLon for geo_types::Point<f64> {
+        fn into_lat_lon(self) -> LatLon {
+            LatLon::new(self.y(), self.x())
+        }
+    }
+
+    impl IntoLatLon for geo_types::Coord<f64> {
+        fn into_lat_lon(self) -> LatLon {
+            LatLon::new(self.y, self.x)
+        }
+    }
+
+    impl From<geo_types::Point<f64>> for LatLon {
+        fn from(point: geo_types::Point<f64>) -> Self {
+            point.into_lat_lon()
+        }
+    }
+
+    impl From<geo_types::Coord<f64>> for LatLon {
+        fn from(coord: geo_types::Coord<f64>) -> Self {
+            coord.into_lat_lon()
+        }
+    }
+
+    impl From<LatLon> for geo_types::Point<f64> {
+        fn from(location: LatLon) -> Self {
+            Self::new(location.longitude, location.latitude)
+        }
+    }
+
+    impl From<LatLon> for geo_types::Coord<f64> {
+        fn from(location: LatLon) -> Self {
+            Self {
+                x: location.longitude,
+                y: location.latitude,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "geoutils")]
+mod geoutils_impls {
+    use super::{IntoLatLon, LatLon};
+
+    impl IntoLatLon for geoutils::Location {
+        fn into_lat_lon(self) -> LatLon {
+            LatLon::new(self.latitude(), self.longitude())
+        }
+    }
+
+    impl From<geoutils::Location> for LatLon {
+        fn from(location: geoutils::Location) -> Self {
+            location.into_lat_lon()
+        }
+    }
+
+    impl From<LatLon> for geoutils::Location {
+        fn from(location: LatLon) -> Self {
+            Self::new(location.latitude, location.longitude)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_distance_to() {
+        let hamburg = LatLon::new(53.5511, 9.9937);
+        assert_eq!(hamburg.distance_to(hamburg), 0.0);
+        assert_eq!(hamburg.distance_to((53.5511, 9.9937)), 0.0);
+        assert_eq!(hamburg.distance_to(LatLon::new(-53.5511, -170.0063)), 20_015_086.796);
+
+        let boston = (42.3601, -71.0589);
+        assert_eq!(hamburg.distance_to(boston), LatLon::from(boston).distance_to(hamburg));
+    }
+
+    #[test]
+    fn test_const_haversine() {
+        let hamburg = LatLon::new(53.5511, 9.9937);
+        for (latitude, longitude) in [
+            (42.3601, -71.0589),
+            (-53.5511, -170.0063),
+            (53.5511, 9.9938),
+            (-33.9, 151.2),
+        ] {
+            let to = LatLon::new(latitude, longitude);
+            let haversine = libm::pow(libm::sin(hamburg.distance_to(to) / EARTH_RADIUS / 2.0), 2.0);
+            assert!((hamburg.const_haversine(to) - haversine).abs() < 1e-9, "{to:?}");
+        }
+        assert_eq!(normalize_degrees(-350.0), 10.0);
+        assert_eq!(normalize_degrees(540.0), 180.0);
+    }
+
+    #[test]
+    fn test_into_lat_lon() {
+        let location = LatLon::new(53.5511, 9.9937);
+        assert_eq!((53.5511, 9.9937).into_lat_lon(), location);
+        assert_eq!((53.5511_f32, 9.9937_f32).into_lat_lon().latitude() as f32, 53.5511);
+        assert_eq!((&location).into_lat_lon(), location);
+        assert_eq!(<(f64, f64)>::from(location), (53.5511, 9.9937));
+    }
+
+    #[cfg(feature = "geo-types")]
+    #[test]
+    fn test_geo_types() {
+        let location = LatLon::new(53.5511, 9.9937);
+        let point = geo_types::Point::new(9.9937, 53.5511);
+        assert_eq!(point.into_lat_lon(), location);
+        assert_eq!(geo_types::Point::from(location), point);
+        assert_eq!(LatLon::from(geo_types::Coord::from(location)), location);
+    }
+
+    #[cfg(feature = "geoutils")]
+    #[test]
+    fn test_geoutils() {
+        let location = LatLon::new(53.5511, 9.9937);
+        let other = geoutils::Location::new(42.3601, -71.0589);
+        assert_eq!(LatLon::from(geoutils::Location::from(location)), location);
+        assert_eq!(
+            location.distance_to(other),
+            geoutils::Location::from(location)
+                .haversine_distance_to(&other)
+                .meters()
+        );
+    }
+}
@@ -3,7 +3,13 @@
 //! Especially useful when you run code at the edge and want fast access to
 //! regional AWS services, e.g. Cloudflare Workers accessing DynamoDB global
 //! tables.
+//!
+//! Without the default `std` feature, the crate is `no_std` and the core
+//! lookups, i.e. finding regions and parsing region names, codes and
+//! coordinates, don't allocate. Group and replica searches, coverage
+//! analysis, geohashes and location headers require `std`.
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![deny(clippy::all, clippy::nursery)]
 #![deny(nonstandard_style, rust_2018_idioms)]
 #![deny(missing_docs, missing_debug_implementations)]
@@ -11,10 +17,98 @@
 mod error;
 pub use error::Error;
 
+mod latlon;
+pub use latlon::*;
+
 mod aws;
 pub use aws::*;
 
+mod naming;
+pub use naming::*;
+
+mod availability_zone;
+pub use availability_zone::*;
+
+mod zone;
+pub use zone::*;
+
+mod endpoint;
+pub use endpoint::*;
+
+mod service;
+pub use service::*;
+
+mod carbon;
+pub use carbon::*;
+
+mod sticky;
+pub use sticky::*;
+
+#[cfg(feature = "std")]
+mod group;
+#[cfg(feature = "std")]
+pub use group::*;
+
+#[cfg(feature = "std")]
+mod placement;
+#[cfg(feature = "std")]
+pub use placement::*;
+
+#[cfg(feature = "std")]
+mod coverage;
+#[cfg(feature = "std")]
+pub use coverage::*;
+
+mod coordinates;
+pub use coordinates::*;
+
+#[cfg(feature = "std")]
+mod geohash;
+#[cfg(feature = "std")]
+pub use geohash::*;
+
+mod country;
+pub use country::*;
+
+mod subdivision;
+pub use subdivision::*;
+
+mod timezone;
+pub use timezone::*;
+
+mod airport;
+pub use airport::*;
+
+mod cloudfront;
+pub use cloudfront::*;
+
+#[cfg(feature = "std")]
+mod headers;
+#[cfg(feature = "std")]
+pub use headers::*;
+
+#[cfg(feature = "std")]
+mod stats;
+
+#[cfg(feature = "aws-types")]
+mod sdk;
+#[cfg(feature = "aws-config")]
+pub use sdk::*;
+
 #[cfg(feature = "deno")]
 mod deno;
 #[cfg(feature = "deno")]
 pub use deno::*;
+
+#[cfg(feature = "gazetteer")]
+mod gazetteer;
+#[cfg(feature = "gazetteer")]
+pub use gazetteer::*;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[cfg(feature = "maxmind")]
+mod maxmind;
+#[cfg(feature = "maxmind")]
+pub use maxmind::*;
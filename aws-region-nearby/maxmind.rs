@@ -0,0 +1,269 @@
+use std::fmt;
+use std::net::IpAddr;
+use std::path::Path;
+
+use maxminddb::{geoip2, MaxMindDBError, Reader};
+
+use crate::aws::AwsRegion;
+use crate::country::Country;
+use crate::latlon::{IntoLatLon, LatLon};
+use crate::Error;
+
+/// An offline IP geolocation database in MaxMind DB format, e.g. GeoLite2 City
+/// or DB-IP City Lite.
+pub struct GeoIpDatabase {
+    reader: Reader<Vec<u8>>,
+}
+
+/// The location of an IP address according to a [`GeoIpDatabase`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IpLocation {
+    /// The approximate latitude of the IP address.
+    pub latitude: f64,
+
+    /// The approximate longitude of the IP address.
+    pub longitude: f64,
+
+    /// The ISO 3166-1 alpha-2 country code of the IP address, if known.
+    pub country: Option<String>,
+
+    /// Whether the database only knows the country of the IP address, e.g.
+    /// for many mobile and anycast ranges, so that the coordinates are the
+    /// population-weighted centroid of the country.
+    pub from_country: bool,
+}
+
+impl GeoIpDatabase {
+    /// Opens the `.mmdb` database file at the given path.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let reader = Reader::open_readfile(path).map_err(|e| Error::InvalidGeoIpDatabase(e.to_string()))?;
+        Ok(Self { reader })
+    }
+
+    /// Loads a database from the contents of a `.mmdb` file.
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, Error> {
+        let reader = Reader::from_source(bytes).map_err(|e| Error::InvalidGeoIpDatabase(e.to_string()))?;
+        Ok(Self { reader })
+    }
+
+    /// Returns the type of the database, e.g. `GeoLite2-City`.
+    pub fn database_type(&self) -> &str {
+        &self.reader.metadata.database_type
+    }
+
+    /// Resolves an IP address to its location.
+    ///
+    /// If the database has no coordinates for the IP address, but knows its
+    /// country, the location of the country is used instead. See
+    /// [`IpLocation::from_country`].
+    pub fn lookup(&self, ip: IpAddr) -> Result<IpLocation, Error> {
+        let city: geoip2::City<'_> = self.reader.lookup(ip).map_err(|e| match e {
+            MaxMindDBError::AddressNotFoundError(_) => Error::UnknownIpAddress,
+            e => Error::InvalidGeoIpDatabase(e.to_string()),
+        })?;
+
+        let country = city.country.and_then(|c| c.iso_code).map(str::to_owned);
+        let coordinates = city
+            .location
+            .and_then(|location| location.latitude.zip(location.longitude));
+        if let Some((latitude, longitude)) = coordinates {
+            return Ok(IpLocation {
+                latitude,
+                longitude,
+                country,
+                from_country: false,
+            });
+        }
+
+        let location = country
+            .as_deref()
+            .and_then(|code| code.parse::<Country>().ok())
+            .ok_or(Error::UnknownIpAddress)?
+            .location();
+        Ok(IpLocation {
+            latitude: location.latitude(),
+            longitude: location.longitude(),
+            country,
+            from_country: true,
+        })
+    }
+
+    /// Finds the nearest AWS region to an IP address.
+    pub fn find_region(&self, ip: IpAddr) -> Result<AwsRegion, Error> {
+        let regions: Vec<AwsRegion> = AwsRegion::iter().collect();
+        self.find_region_from_list(ip, &regions)
+    }
+
+    /// Finds the nearest AWS region from a list of regions to an IP address.
+    ///
+    /// If only the country of the IP address is known, this is the region
+    /// serving the country best, see [`Country::find_region_from_list`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if regions is empty.
+    pub fn find_region_from_list(&self, ip: IpAddr, regions: &[AwsRegion]) -> Result<AwsRegion, Error> {
+        let location = self.lookup(ip)?;
+        match location.country.as_deref() {
+            Some(code) if location.from_country => crate::find_region_for_country(code, regions),
+            _ => Ok(crate::find_region_from_list(location, regions)),
+        }
+    }
+}
+
+impl IntoLatLon for &IpLocation {
+    fn into_lat_lon(self) -> LatLon {
+        LatLon::new(self.latitude, self.longitude)
+    }
+}
+
+impl IntoLatLon for IpLocation {
+    fn into_lat_lon(self) -> LatLon {
+        (&self).into_lat_lon()
+    }
+}
+
+impl fmt::Debug for GeoIpDatabase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GeoIpDatabase")
+            .field("database_type", &self.database_type())
+            .field("node_count", &self.reader.metadata.node_count)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    // Contains 89.160.20.0/24 (SE), 81.2.69.0/24 (GB), 216.160.83.0/24 (US),
+    // 175.16.199.0/24 (CN), 2001:218::/32 (JP), without coordinates
+    // 202.196.224.0/24 (PH) and 2a02:3038::/32 (DE), and without a country
+    // 198.51.100.0/24.
+    const FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/testdata/GeoLite2-City-Test.mmdb");
+
+    fn database() -> GeoIpDatabase {
+        GeoIpDatabase::open(FIXTURE).unwrap()
+    }
+
+    #[test]
+    fn test_open() {
+        assert_eq!(database().database_type(), "GeoLite2-City");
+
+        let bytes = std::fs::read(FIXTURE).unwrap();
+        assert_eq!(
+            GeoIpDatabase::from_bytes(bytes).unwrap().database_type(),
+            "GeoLite2-City"
+        );
+
+        assert!(matches!(
+            GeoIpDatabase::open("does-not-exist.mmdb"),
+            Err(Error::InvalidGeoIpDatabase(_))
+        ));
+        assert!(matches!(
+            GeoIpDatabase::from_bytes(b"not a database".to_vec()),
+            Err(Error::InvalidGeoIpDatabase(_))
+        ));
+    }
+
+    #[test]
+    fn test_lookup() {
+        let db = database();
+
+        assert_eq!(
+            db.lookup("81.2.69.142".parse().unwrap()),
+            Ok(IpLocation {
+                latitude: 51.5142,
+                longitude: -0.0931,
+                country: Some("GB".to_string()),
+                from_country: false,
+            })
+        );
+        assert_eq!(
+            db.lookup("2001:218:85a3::1".parse().unwrap()).unwrap().country,
+            Some("JP".to_string())
+        );
+
+        assert_eq!(db.lookup("10.0.0.1".parse().unwrap()), Err(Error::UnknownIpAddress));
+        assert_eq!(db.lookup("198.51.100.7".parse().unwrap()), Err(Error::UnknownIpAddress));
+    }
+
+    #[test]
+    fn test_lookup_falls_back_to_country() {
+        let db = database();
+
+        for (ip, code) in [("202.196.224.1", "PH"), ("2a02:3038::1", "DE")] {
+            let location = db.lookup(ip.parse().unwrap()).unwrap();
+            let country: Country = code.parse().unwrap();
+            assert_eq!(
+                location,
+                IpLocation {
+                    latitude: country.location().latitude(),
+                    longitude: country.location().longitude(),
+                    country: Some(code.to_string()),
+                    from_country: true,
+                },
+                "{ip}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_find_region() {
+        let db = database();
+
+        assert_eq!(
+            db.find_region("89.160.20.112".parse().unwrap()),
+            Ok(AwsRegion::EuNorth1)
+        );
+        assert_eq!(db.find_region("216.160.83.56".parse().unwrap()), Ok(AwsRegion::UsWest2));
+        assert_eq!(
+            db.find_region("10.0.0.1".parse().unwrap()),
+            Err(Error::UnknownIpAddress)
+        );
+    }
+
+    #[test]
+    fn test_find_region_from_list() {
+        struct Test {
+            ip: &'static str,
+            list: Vec<AwsRegion>,
+            region: AwsRegion,
+        }
+
+        let tests = vec![
+            Test {
+                ip: "81.2.69.142",
+                list: vec![AwsRegion::EuWest1, AwsRegion::EuCentral1],
+                region: AwsRegion::EuWest1,
+            },
+            Test {
+                ip: "175.16.199.1",
+                list: vec![AwsRegion::ApNortheast1, AwsRegion::CnNorth1],
+                region: AwsRegion::CnNorth1,
+            },
+            Test {
+                ip: "2001:218::1",
+                list: vec![AwsRegion::UsWest2, AwsRegion::ApNortheast1],
+                region: AwsRegion::ApNortheast1,
+            },
+            Test {
+                ip: "202.196.224.1",
+                list: vec![AwsRegion::ApSoutheast1, AwsRegion::ApSoutheast2],
+                region: AwsRegion::ApSoutheast1,
+            },
+            Test {
+                ip: "2a02:3038::1",
+                list: vec![AwsRegion::EuWest1, AwsRegion::EuCentral1],
+                region: AwsRegion::EuCentral1,
+            },
+        ];
+
+        for t in tests {
+            let region = database().find_region_from_list(t.ip.parse().unwrap(), &t.list);
+            assert_eq!(region, Ok(t.region), "{}", t.ip);
+        }
+    }
+}
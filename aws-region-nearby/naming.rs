@@ -0,0 +1,169 @@
+use core::fmt;
+
+use crate::aws::AwsRegion;
+
+/// A naming convention for AWS regions, e.g. in resource names.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum RegionStyle {
+    /// The region name, e.g. `us-east-1`.
+    Name,
+
+    /// The short code used in AZ IDs, e.g. `use1`.
+    ShortCode,
+
+    /// The IATA code of an airport near the region, e.g. `IAD`, as used by
+    /// AWS for data centers.
+    AirportCode,
+}
+
+impl AwsRegion {
+    /// Returns the IATA code of an airport near the region, e.g. `IAD` for
+    /// us-east-1.
+    pub const fn airport_code(&self) -> &'static str {
+        match *self {
+            Self::AfSouth1 => "CPT",
+            Self::ApEast1 => "HKG",
+            Self::ApNortheast1 => "NRT",
+            Self::ApNortheast2 => "ICN",
+            Self::ApNortheast3 => "KIX",
+            Self::ApSouth1 => "BOM",
+            Self::ApSoutheast1 => "SIN",
+            Self::ApSoutheast2 => "SYD",
+            Self::ApSoutheast3 => "CGK",
+            Self::CaCentral1 => "YUL",
+            Self::CnNorth1 => "BJS",
+            Self::CnNorthwest1 => "ZHY",
+            Self::EuCentral1 => "FRA",
+            Self::EuNorth1 => "ARN",
+            Self::EuSouth1 => "MXP",
+            Self::EuWest1 => "DUB",
+            Self::EuWest2 => "LHR",
+            Self::EuWest3 => "CDG",
+            Self::MeCentral1 => "DXB",
+            Self::MeSouth1 => "BAH",
+            Self::SaEast1 => "GRU",
+            Self::UsEast1 => "IAD",
+            Self::UsEast2 => "CMH",
+            Self::UsWest1 => "SFO",
+            Self::UsWest2 => "PDX",
+            Self::UsGovEast1 => "OSU",
+            Self::UsGovWest1 => "PDT",
+        }
+    }
+
+    /// Returns the region with the given airport code, ignoring ASCII case.
+    pub fn from_airport_code(code: &str) -> Option<Self> {
+        Self::iter().find(|region| region.airport_code().eq_ignore_ascii_case(code))
+    }
+
+    /// Returns the region with the given name, short code or airport code,
+    /// ignoring ASCII case.
+    ///
+    /// ```
+    /// use aws_region_nearby::AwsRegion;
+    ///
+    /// assert_eq!(AwsRegion::from_alias("eu-west-1"), Some(AwsRegion::EuWest1));
+    /// assert_eq!(AwsRegion::from_alias("euw1"), Some(AwsRegion::EuWest1));
+    /// assert_eq!(AwsRegion::from_alias("DUB"), Some(AwsRegion::EuWest1));
+    /// ```
+    pub fn from_alias(alias: &str) -> Option<Self> {
+        Self::from_name(alias)
+            .or_else(|| Self::from_short_code(alias))
+            .or_else(|| Self::from_airport_code(alias))
+    }
+
+    /// Returns the name of the region in the given style.
+    pub const fn name_in(&self, style: RegionStyle) -> &'static str {
+        match style {
+            RegionStyle::Name => self.name(),
+            RegionStyle::ShortCode => self.short_code(),
+            RegionStyle::AirportCode => self.airport_code(),
+        }
+    }
+
+    /// Returns an object that formats the region in the given style.
+    ///
+    /// ```
+    /// use aws_region_nearby::{AwsRegion, RegionStyle};
+    ///
+    /// let region = AwsRegion::ApSoutheast2;
+    /// assert_eq!(format!("app-{}", region.display(RegionStyle::ShortCode)), "app-apse2");
+    /// assert_eq!(format!("{:<5}|", region.display(RegionStyle::AirportCode)), "SYD  |");
+    /// ```
+    pub const fn display(&self, style: RegionStyle) -> RegionDisplay {
+        RegionDisplay { region: *self, style }
+    }
+}
+
+/// Formats a region in a [`RegionStyle`], see [`AwsRegion::display`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RegionDisplay {
+    region: AwsRegion,
+    style: RegionStyle,
+}
+
+impl fmt::Display for RegionDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.region.name_in(self.style))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const STYLES: [RegionStyle; 3] = [RegionStyle::Name, RegionStyle::ShortCode, RegionStyle::AirportCode];
+
+    #[test]
+    fn test_name_in() {
+        struct Test {
+            region: AwsRegion,
+            names: [&'static str; 3],
+        }
+
+        let tests = vec![
+            Test {
+                region: AwsRegion::UsEast1,
+                names: ["us-east-1", "use1", "IAD"],
+            },
+            Test {
+                region: AwsRegion::EuCentral1,
+                names: ["eu-central-1", "euc1", "FRA"],
+            },
+            Test {
+                region: AwsRegion::ApNortheast1,
+                names: ["ap-northeast-1", "apne1", "NRT"],
+            },
+            Test {
+                region: AwsRegion::UsGovWest1,
+                names: ["us-gov-west-1", "usgw1", "PDT"],
+            },
+        ];
+
+        for t in tests {
+            let names = STYLES.map(|style| t.region.name_in(style));
+            assert_eq!(names, t.names);
+            let names = STYLES.map(|style| t.region.display(style).to_string());
+            assert_eq!(names, t.names);
+        }
+    }
+
+    #[test]
+    fn test_from_alias() {
+        for region in AwsRegion::iter() {
+            for style in STYLES {
+                let alias = region.name_in(style);
+                assert_eq!(AwsRegion::from_alias(alias), Some(region), "{alias}");
+                assert_eq!(AwsRegion::from_alias(&alias.to_lowercase()), Some(region), "{alias}");
+            }
+        }
+
+        assert_eq!(AwsRegion::from_airport_code("dub"), Some(AwsRegion::EuWest1));
+        assert_eq!(AwsRegion::from_airport_code("euw1"), None);
+        assert_eq!(AwsRegion::from_alias("JFK"), None);
+        assert_eq!(AwsRegion::from_alias("us-east"), None);
+    }
+}
@@ -0,0 +1,165 @@
+use crate::aws::AwsRegion;
+use crate::group::GroupObjective;
+use crate::latlon::{IntoLatLon, LatLon};
+
+/// A replica added by [`optimize_replica_regions`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlacementStep {
+    /// The region added in this step.
+    pub region: AwsRegion,
+
+    /// The value of the objective after adding the region, in meters.
+    pub score: f64,
+
+    /// How much the score improved over the previous step, in meters. `None`
+    /// for the first replica.
+    pub improvement: Option<f64>,
+}
+
+/// The result of a replica placement optimization.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReplicaPlacement {
+    /// The chosen replica regions, in the order they were added.
+    pub regions: Vec<AwsRegion>,
+
+    /// The value of the objective for all chosen regions, in meters.
+    pub score: f64,
+
+    /// One step per chosen region, in the order they were added.
+    pub steps: Vec<PlacementStep>,
+}
+
+/// Chooses up to `k` replica regions for a user distribution given as
+/// `(location, weight)` pairs.
+///
+/// Each user is served by their nearest replica, and the objective is applied
+/// to the resulting distances: [`GroupObjective::Mean`] solves the k-median
+/// problem, [`GroupObjective::Max`] the k-center problem. The `required`
+/// regions (e.g. the home region of a global table) are always placed first,
+/// even if there are more of them than `k`. The remaining regions are then
+/// picked greedily from `candidates`, one at a time, each minimizing the
+/// objective.
+///
+/// # Panics
+///
+/// Panics if locations is empty.
+pub fn optimize_replica_regions<L: IntoLatLon + Copy>(
+    locations: &[(L, f64)],
+    candidates: &[AwsRegion],
+    required: &[AwsRegion],
+    k: usize,
+    objective: GroupObjective,
+) -> ReplicaPlacement {
+    assert!(!locations.is_empty(), "locations must not be empty");
+
+    let points: Vec<LatLon> = locations.iter().map(|(location, _)| location.into_lat_lon()).collect();
+    let weights: Vec<f64> = locations.iter().map(|&(_, weight)| weight).collect();
+    let served_by = |region: &AwsRegion, nearest: &[f64]| -> Vec<f64> {
+        points
+            .iter()
+            .zip(nearest)
+            .map(|(point, distance)| region.distance_to(point).min(*distance))
+            .collect()
+    };
+
+    let mut regions: Vec<AwsRegion> = Vec::new();
+    let mut steps: Vec<PlacementStep> = Vec::new();
+    let mut nearest = vec![f64::INFINITY; points.len()];
+
+    loop {
+        let next = match required.iter().find(|region| !regions.contains(region)) {
+            Some(region) => {
+                let distances = served_by(region, &nearest);
+                let score = objective.score(&distances, &weights);
+                Some((*region, distances, score))
+            }
+            None if regions.len() < k => candidates
+                .iter()
+                .filter(|region| !regions.contains(region))
+                .map(|region| {
+                    let distances = served_by(region, &nearest);
+                    let score = objective.score(&distances, &weights);
+                    (*region, distances, score)
+                })
+                .min_by_key(|(_, _, score)| ordered_float::OrderedFloat(*score)),
+            None => None,
+        };
+
+        let Some((region, distances, score)) = next else {
+            break;
+        };
+
+        steps.push(PlacementStep {
+            region,
+            score,
+            improvement: steps.last().map(|step| step.score - score),
+        });
+        regions.push(region);
+        nearest = distances;
+    }
+
+    ReplicaPlacement {
+        score: steps.last().map_or(0.0, |step| step.score),
+        regions,
+        steps,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const USERS: [((f64, f64), f64); 5] = [
+        ((53.5511, 9.9937), 3.0),    // Hamburg
+        ((48.1351, 11.5820), 2.0),   // Munich
+        ((40.7128, -74.0060), 2.0),  // New York
+        ((35.6762, 139.6503), 1.0),  // Tokyo
+        ((-33.8688, 151.2093), 0.5), // Sydney
+    ];
+
+    #[test]
+    fn test_optimize_replica_regions() {
+        let candidates: Vec<AwsRegion> = AwsRegion::iter().collect();
+
+        let placement = optimize_replica_regions(&USERS, &candidates, &[], 3, GroupObjective::Mean);
+        assert_eq!(
+            placement.regions,
+            vec![AwsRegion::EuCentral1, AwsRegion::ApNortheast1, AwsRegion::UsEast1]
+        );
+        assert_eq!(placement.steps.len(), 3);
+        assert_eq!(placement.steps[0].improvement, None);
+        assert!(placement.steps[1..].iter().all(|step| step.improvement.unwrap() > 0.0));
+        assert_eq!(placement.score, placement.steps[2].score);
+    }
+
+    #[test]
+    fn test_optimize_replica_regions_with_required() {
+        let candidates = [AwsRegion::UsEast1, AwsRegion::ApSoutheast1, AwsRegion::ApSoutheast2];
+
+        let placement = optimize_replica_regions(&USERS, &candidates, &[AwsRegion::EuCentral1], 2, GroupObjective::Max);
+        assert_eq!(placement.regions, vec![AwsRegion::EuCentral1, AwsRegion::ApSoutheast1]);
+        assert_eq!(placement.steps[0].region, AwsRegion::EuCentral1);
+        assert_eq!(placement.steps[0].improvement, None);
+    }
+
+    #[test]
+    fn test_optimize_replica_regions_budget_exceeds_candidates() {
+        let candidates = [AwsRegion::EuCentral1, AwsRegion::EuCentral1];
+
+        let placement = optimize_replica_regions(&USERS, &candidates, &[], 5, GroupObjective::P95);
+        assert_eq!(placement.regions, vec![AwsRegion::EuCentral1]);
+
+        let placement = optimize_replica_regions(&USERS, &candidates, &[], 0, GroupObjective::P95);
+        assert_eq!(placement.regions, vec![]);
+        assert_eq!(placement.score, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "locations must not be empty")]
+    fn test_optimize_replica_regions_without_locations() {
+        optimize_replica_regions::<LatLon>(&[], &[AwsRegion::EuWest1], &[], 1, GroupObjective::Mean);
+    }
+}
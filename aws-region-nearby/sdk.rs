@@ -0,0 +1,173 @@
+use aws_types::region::Region;
+
+use crate::aws::AwsRegion;
+use crate::Error;
+
+impl From<AwsRegion> for Region {
+    fn from(region: AwsRegion) -> Self {
+        Self::from_static(region.name())
+    }
+}
+
+impl TryFrom<&Region> for AwsRegion {
+    type Error = Error;
+
+    fn try_from(region: &Region) -> Result<Self, Error> {
+        region.as_ref().parse()
+    }
+}
+
+impl TryFrom<Region> for AwsRegion {
+    type Error = Error;
+
+    fn try_from(region: Region) -> Result<Self, Error> {
+        Self::try_from(&region)
+    }
+}
+
+#[cfg(feature = "aws-config")]
+mod provider {
+    use aws_config::meta::region::{future, ProvideRegion};
+    use aws_types::region::Region;
+
+    use crate::aws::{find_region_from_list, AwsRegion};
+    use crate::coordinates::Coordinates;
+    use crate::latlon::{IntoLatLon, LatLon};
+
+    /// Where a [`NearestRegionProvider`] gets its location from.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum LocationSource {
+        /// A fixed location.
+        Fixed(LatLon),
+
+        /// The environment variable of the given name, holding coordinates in
+        /// any notation [`Coordinates`] can parse.
+        Env(String),
+    }
+
+    /// A region provider for the AWS SDK for Rust that resolves the nearest
+    /// replica region to a location.
+    ///
+    /// ```no_run
+    /// # async fn example() {
+    /// use aws_config::BehaviorVersion;
+    /// use aws_region_nearby::{aws_region, NearestRegionProvider};
+    ///
+    /// let provider = NearestRegionProvider::from_env("CLIENT_LOCATION")
+    ///     .with_regions(&[aws_region!("us-east-1"), aws_region!("eu-central-1")]);
+    /// let config = aws_config::defaults(BehaviorVersion::latest())
+    ///     .region(provider)
+    ///     .load()
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// If the location is not available, no region is provided, so that the
+    /// provider can be chained with others, e.g. in a
+    /// [`RegionProviderChain`](aws_config::meta::region::RegionProviderChain).
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct NearestRegionProvider {
+        source: LocationSource,
+        regions: Vec<AwsRegion>,
+    }
+
+    impl NearestRegionProvider {
+        /// Creates a provider for the given location source, choosing from all
+        /// regions.
+        pub fn new(source: LocationSource) -> Self {
+            Self {
+                source,
+                regions: AwsRegion::iter().collect(),
+            }
+        }
+
+        /// Creates a provider for a fixed location.
+        pub fn from_location<L: IntoLatLon>(location: L) -> Self {
+            Self::new(LocationSource::Fixed(location.into_lat_lon()))
+        }
+
+        /// Creates a provider that reads coordinates from the given
+        /// environment variable.
+        pub fn from_env(var: impl Into<String>) -> Self {
+            Self::new(LocationSource::Env(var.into()))
+        }
+
+        /// Restricts the provider to a list of regions, e.g. the replica
+        /// regions of a global table.
+        ///
+        /// # Panics
+        ///
+        /// Panics if regions is empty.
+        pub fn with_regions(mut self, regions: &[AwsRegion]) -> Self {
+            assert!(!regions.is_empty(), "regions must not be empty");
+            self.regions = regions.to_vec();
+            self
+        }
+
+        /// Returns the location from the configured source, if available.
+        pub fn location(&self) -> Option<LatLon> {
+            match &self.source {
+                LocationSource::Fixed(location) => Some(*location),
+                LocationSource::Env(var) => std::env::var(var)
+                    .ok()?
+                    .parse::<Coordinates>()
+                    .ok()
+                    .map(|coordinates| coordinates.location()),
+            }
+        }
+
+        /// Resolves the nearest region, if the location is available.
+        pub fn resolve(&self) -> Option<AwsRegion> {
+            self.location()
+                .map(|location| find_region_from_list(location, &self.regions))
+        }
+    }
+
+    impl ProvideRegion for NearestRegionProvider {
+        fn region(&self) -> future::ProvideRegion<'_> {
+            future::ProvideRegion::ready(self.resolve().map(Region::from))
+        }
+    }
+}
+
+#[cfg(feature = "aws-config")]
+pub use provider::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_region_conversion() {
+        let region = Region::from(AwsRegion::EuCentral1);
+        assert_eq!(region, Region::new("eu-central-1"));
+        assert_eq!(AwsRegion::try_from(&region), Ok(AwsRegion::EuCentral1));
+        assert_eq!(AwsRegion::try_from(Region::new("US-WEST-2")), Ok(AwsRegion::UsWest2));
+        assert_eq!(AwsRegion::try_from(Region::new("mars-1")), Err(Error::InvalidAwsRegion));
+    }
+
+    #[cfg(feature = "aws-config")]
+    #[test]
+    fn test_nearest_region_provider() {
+        use std::future::Future;
+        use std::pin::pin;
+        use std::task::{Context, Poll, Waker};
+
+        use aws_config::meta::region::ProvideRegion;
+
+        let hamburg = (53.5511, 9.9937);
+        let provider = NearestRegionProvider::from_location(hamburg);
+        assert_eq!(provider.resolve(), Some(AwsRegion::EuCentral1));
+
+        let provider = provider.with_regions(&[AwsRegion::UsEast1, AwsRegion::EuWest1]);
+        assert_eq!(provider.resolve(), Some(AwsRegion::EuWest1));
+
+        let region = pin!(provider.region()).poll(&mut Context::from_waker(Waker::noop()));
+        assert_eq!(region, Poll::Ready(Some(Region::new("eu-west-1"))));
+
+        let provider = NearestRegionProvider::from_env("AWS_REGION_NEARBY_TEST_UNSET");
+        assert_eq!(provider.location(), None);
+        assert_eq!(provider.resolve(), None);
+    }
+}
@@ -0,0 +1,359 @@
+//! Serde support and alternative representations for use with
+//! `#[serde(with = "...")]`.
+//!
+//! With the `serde` feature, regions and the other named types of this crate
+//! are (de)serialized as their canonical names, e.g. `"us-east-1"`, `"DE"` or
+//! `"Europe/Berlin"`, and search results as plain structs.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use ::serde::de::{self, Unexpected, Visitor};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::airport::Airport;
+use crate::availability_zone::AvailabilityZone;
+use crate::aws::AwsRegion;
+use crate::coordinates::Coordinates;
+use crate::country::Country;
+#[cfg(feature = "deno")]
+use crate::deno::DenoRegion;
+#[cfg(feature = "gazetteer")]
+use crate::gazetteer::City;
+use crate::service::Service;
+use crate::subdivision::Subdivision;
+use crate::timezone::Timezone;
+use crate::zone::{AwsZone, RoutingTarget};
+
+macro_rules! serde_via_str {
+    ($($(#[$attr:meta])* $type:ty => $expecting:literal,)*) => {$(
+        $(#[$attr])*
+        impl Serialize for $type {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        $(#[$attr])*
+        impl<'de> Deserialize<'de> for $type {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_str(FromStrVisitor::new($expecting))
+            }
+        }
+    )*};
+}
+
+serde_via_str! {
+    AwsRegion => "an AWS region name",
+    AwsZone => "an AWS zone name or ID",
+    AvailabilityZone => "an AZ ID",
+    RoutingTarget => "an AWS region or zone name",
+    #[cfg(feature = "deno")]
+    DenoRegion => "a Deno Deploy region name",
+    Country => "an ISO 3166-1 country code",
+    Subdivision => "an ISO 3166-2 subdivision code",
+    Timezone => "an IANA time zone",
+    Airport => "an IATA airport code",
+    Coordinates => "coordinates",
+    Service => "an AWS service name",
+}
+
+struct FromStrVisitor<T> {
+    expecting: &'static str,
+    marker: PhantomData<T>,
+}
+
+impl<T> FromStrVisitor<T> {
+    const fn new(expecting: &'static str) -> Self {
+        Self {
+            expecting,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: FromStr> Visitor<'_> for FromStrVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<T, E> {
+        s.parse().map_err(|_| E::invalid_value(Unexpected::Str(s), &self))
+    }
+}
+
+#[cfg(feature = "gazetteer")]
+impl<'de> Deserialize<'de> for City {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct NamedCity {
+            name: String,
+            country: String,
+        }
+
+        let city = NamedCity::deserialize(deserializer)?;
+        Self::find(&format!("{}, {}", city.name, city.country))
+            .map_err(|_| de::Error::invalid_value(Unexpected::Str(&city.name), &"a city in the gazetteer"))
+    }
+}
+
+/// (De)serializes region names in SCREAMING_SNAKE case, e.g. `US_EAST_1`
+/// instead of `us-east-1`, as used by some config formats and enums in other
+/// languages.
+///
+/// ```
+/// # use aws_region_nearby::AwsRegion;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Config {
+///     #[serde(with = "aws_region_nearby::serde::screaming_snake")]
+///     home_region: AwsRegion,
+/// }
+/// ```
+pub mod screaming_snake {
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    use ::serde::de::{self, Unexpected};
+    use ::serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serializes a region name in SCREAMING_SNAKE case.
+    pub fn serialize<T: Display, S: Serializer>(region: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&region.to_string().replace('-', "_").to_uppercase())
+    }
+
+    /// Deserializes a region name in SCREAMING_SNAKE case. Like parsing,
+    /// this is case-insensitive.
+    pub fn deserialize<'de, T: FromStr, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.replace('_', "-")
+            .parse()
+            .map_err(|_| de::Error::invalid_value(Unexpected::Str(&s), &"a region name in SCREAMING_SNAKE case"))
+    }
+}
+
+/// (De)serializes regions as short codes, e.g. `use1` instead of
+/// `us-east-1`. Deserializes any alias accepted by
+/// [`AwsRegion::from_alias`].
+///
+/// ```
+/// # use aws_region_nearby::AwsRegion;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Stack {
+///     #[serde(with = "aws_region_nearby::serde::short_code")]
+///     region: AwsRegion,
+/// }
+/// ```
+pub mod short_code {
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{AwsRegion, RegionStyle};
+
+    /// Serializes a region as its short code.
+    pub fn serialize<S: Serializer>(region: &AwsRegion, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(region.name_in(RegionStyle::ShortCode))
+    }
+
+    /// Deserializes a region from its name, short code or airport code.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<AwsRegion, D::Error> {
+        super::deserialize_alias(deserializer)
+    }
+}
+
+/// (De)serializes regions as airport codes, e.g. `IAD` instead of
+/// `us-east-1`. Deserializes any alias accepted by
+/// [`AwsRegion::from_alias`].
+pub mod airport_code {
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::{AwsRegion, RegionStyle};
+
+    /// Serializes a region as its airport code.
+    pub fn serialize<S: Serializer>(region: &AwsRegion, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(region.name_in(RegionStyle::AirportCode))
+    }
+
+    /// Deserializes a region from its name, short code or airport code.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<AwsRegion, D::Error> {
+        super::deserialize_alias(deserializer)
+    }
+}
+
+fn deserialize_alias<'de, D: Deserializer<'de>>(deserializer: D) -> Result<AwsRegion, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    AwsRegion::from_alias(&s)
+        .ok_or_else(|| de::Error::invalid_value(Unexpected::Str(&s), &"an AWS region name or code"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::{Error, GroupObjective, GroupRegion, LatLon};
+
+    #[test]
+    fn test_serialize_names() {
+        assert_eq!(serde_json::to_string(&AwsRegion::UsEast1).unwrap(), r#""us-east-1""#);
+        assert_eq!(
+            serde_json::to_string(&[AwsRegion::EuWest1, AwsRegion::ApSoutheast2]).unwrap(),
+            r#"["eu-west-1","ap-southeast-2"]"#
+        );
+        assert_eq!(
+            serde_json::to_string(&"de".parse::<Country>().unwrap()).unwrap(),
+            r#""DE""#
+        );
+        assert_eq!(
+            serde_json::to_string(&"Asia/Calcutta".parse::<Timezone>().unwrap()).unwrap(),
+            r#""Asia/Kolkata""#
+        );
+        assert_eq!(
+            serde_json::to_string(&Coordinates::new(40.71, -74.0).unwrap()).unwrap(),
+            r#""40.71,-74""#
+        );
+    }
+
+    #[test]
+    fn test_deserialize_names() {
+        assert_eq!(
+            serde_json::from_str::<AwsRegion>(r#""eu-central-1""#).unwrap(),
+            AwsRegion::EuCentral1
+        );
+        assert_eq!(
+            serde_json::from_str::<Subdivision>(r#""US-CA""#).unwrap(),
+            "US-CA".parse::<Subdivision>().unwrap()
+        );
+        assert_eq!(serde_json::from_str::<Airport>(r#""fra""#).unwrap().code(), "FRA");
+        assert_eq!(
+            serde_json::from_str::<Coordinates>(r#""geo:40.71,-74.00""#).unwrap(),
+            Coordinates::new(40.71, -74.0).unwrap()
+        );
+
+        let err = serde_json::from_str::<AwsRegion>(r#""mars-north-1""#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"invalid value: string "mars-north-1", expected an AWS region name at line 1 column 14"#
+        );
+        assert!(serde_json::from_str::<AwsRegion>("42").is_err());
+    }
+
+    #[cfg(feature = "deno")]
+    #[test]
+    fn test_deno_region() {
+        let json = serde_json::to_string(&DenoRegion::EuropeWest3).unwrap();
+        assert_eq!(json, r#""europe-west3""#);
+        assert_eq!(
+            serde_json::from_str::<DenoRegion>(&json).unwrap(),
+            DenoRegion::EuropeWest3
+        );
+    }
+
+    #[test]
+    fn test_errors_and_results() {
+        let json = serde_json::to_string(&Error::InvalidAwsRegion).unwrap();
+        assert_eq!(json, r#""InvalidAwsRegion""#);
+        assert_eq!(serde_json::from_str::<Error>(&json).unwrap(), Error::InvalidAwsRegion);
+
+        let group = GroupRegion {
+            region: AwsRegion::EuWest1,
+            score: 1.5,
+            distances: vec![1.0, 2.0],
+        };
+        let json = serde_json::to_string(&group).unwrap();
+        assert_eq!(json, r#"{"region":"eu-west-1","score":1.5,"distances":[1.0,2.0]}"#);
+        assert_eq!(serde_json::from_str::<GroupRegion>(&json).unwrap(), group);
+
+        assert_eq!(serde_json::to_string(&GroupObjective::P95).unwrap(), r#""P95""#);
+        assert_eq!(
+            serde_json::to_string(&LatLon::new(53.5511, 9.9937)).unwrap(),
+            r#"{"latitude":53.5511,"longitude":9.9937}"#
+        );
+    }
+
+    #[test]
+    fn test_location_hint() {
+        use crate::{location_hints, Confidence, HintKind, LocationHint};
+
+        let hints = location_hints([("cf-ipcountry", "de"), ("x-forwarded-for", "203.0.113.7")]);
+        let json = serde_json::to_string(&hints).unwrap();
+        assert_eq!(
+            json,
+            r#"[{"kind":{"Country":"DE"},"confidence":"Low","header":"cf-ipcountry"},{"kind":{"IpAddress":"203.0.113.7"},"confidence":"Low","header":"x-forwarded-for"}]"#
+        );
+        assert_eq!(serde_json::from_str::<Vec<LocationHint>>(&json).unwrap(), hints);
+
+        let hint = serde_json::from_str::<LocationHint>(
+            r#"{"kind":{"Country":"DE"},"confidence":"Low","header":"CF-IPCountry"}"#,
+        )
+        .unwrap();
+        assert_eq!(hint.kind, HintKind::Country("DE".to_string()));
+        assert_eq!(hint.confidence, Confidence::Low);
+        assert_eq!(hint.header, "cf-ipcountry");
+
+        assert!(serde_json::from_str::<LocationHint>(
+            r#"{"kind":{"Country":"DE"},"confidence":"Low","header":"x-unknown"}"#
+        )
+        .is_err());
+    }
+
+    #[cfg(feature = "gazetteer")]
+    #[test]
+    fn test_city() {
+        let city = City::find("Portland, US").unwrap();
+        let json = serde_json::to_string(&city).unwrap();
+        assert_eq!(serde_json::from_str::<City>(&json).unwrap(), city);
+
+        let city = serde_json::from_str::<City>(r#"{"name":"Sao Paulo","country":"BR"}"#).unwrap();
+        assert_eq!(city.name(), "São Paulo");
+
+        assert!(serde_json::from_str::<City>(r#"{"name":"Atlantis","country":"GR"}"#).is_err());
+    }
+
+    #[test]
+    fn test_screaming_snake() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Config {
+            #[serde(with = "screaming_snake")]
+            home: AwsRegion,
+            replicas: Vec<AwsRegion>,
+        }
+
+        let config = Config {
+            home: AwsRegion::UsGovWest1,
+            replicas: vec![AwsRegion::EuWest1],
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(json, r#"{"home":"US_GOV_WEST_1","replicas":["eu-west-1"]}"#);
+        assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+
+        assert!(serde_json::from_str::<Config>(r#"{"home":"US_MARS_1","replicas":[]}"#).is_err());
+    }
+
+    #[test]
+    fn test_region_codes() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Stack {
+            #[serde(with = "short_code")]
+            region: AwsRegion,
+            #[serde(with = "airport_code")]
+            backup: AwsRegion,
+        }
+
+        let stack = Stack {
+            region: AwsRegion::EuWest1,
+            backup: AwsRegion::EuCentral1,
+        };
+        let json = serde_json::to_string(&stack).unwrap();
+        assert_eq!(json, r#"{"region":"euw1","backup":"FRA"}"#);
+        assert_eq!(serde_json::from_str::<Stack>(&json).unwrap(), stack);
+        assert_eq!(
+            serde_json::from_str::<Stack>(r#"{"region":"eu-west-1","backup":"euc1"}"#).unwrap(),
+            stack
+        );
+
+        let err = serde_json::from_str::<Stack>(r#"{"region":"xyz1","backup":"FRA"}"#).unwrap_err();
+        assert!(err.to_string().contains("expected an AWS region name or code"));
+    }
+}
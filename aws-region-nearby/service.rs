@@ -0,0 +1,292 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::aws::AwsRegion;
+use crate::latlon::IntoLatLon;
+use crate::Error;
+
+/// An AWS service, or a feature of one, whose availability differs between
+/// regions.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Service {
+    /// AWS App Runner
+    AppRunner,
+
+    /// Amazon Bedrock
+    Bedrock,
+
+    /// Amazon DynamoDB
+    DynamoDb,
+
+    /// Amazon DynamoDB global tables (version 2019.11.21)
+    DynamoDbGlobalTables,
+
+    /// Amazon Kinesis Data Streams
+    Kinesis,
+
+    /// AWS Lambda
+    Lambda,
+
+    /// Amazon S3
+    S3,
+
+    /// Amazon Simple Email Service
+    Ses,
+
+    /// Amazon Simple Notification Service
+    Sns,
+
+    /// Amazon Simple Queue Service
+    Sqs,
+}
+
+const SERVICES: [Service; 10] = [
+    Service::AppRunner,
+    Service::Bedrock,
+    Service::DynamoDb,
+    Service::DynamoDbGlobalTables,
+    Service::Kinesis,
+    Service::Lambda,
+    Service::S3,
+    Service::Ses,
+    Service::Sns,
+    Service::Sqs,
+];
+
+impl Service {
+    /// Returns an iterator over all services.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        SERVICES.iter().copied()
+    }
+
+    /// Returns the name of the service, e.g. `dynamodb`.
+    pub const fn name(&self) -> &'static str {
+        match *self {
+            Self::AppRunner => "apprunner",
+            Self::Bedrock => "bedrock",
+            Self::DynamoDb => "dynamodb",
+            Self::DynamoDbGlobalTables => "dynamodb-global-tables",
+            Self::Kinesis => "kinesis",
+            Self::Lambda => "lambda",
+            Self::S3 => "s3",
+            Self::Ses => "ses",
+            Self::Sns => "sns",
+            Self::Sqs => "sqs",
+        }
+    }
+
+    /// Returns an iterator over the regions supporting the service.
+    pub fn regions(&self) -> impl Iterator<Item = AwsRegion> + '_ {
+        AwsRegion::iter().filter(|region| region.supports(*self))
+    }
+}
+
+impl fmt::Display for Service {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Service {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Self::iter()
+            .find(|service| service.name().eq_ignore_ascii_case(s))
+            .ok_or(Error::InvalidService)
+    }
+}
+
+impl TryFrom<&str> for Service {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Error> {
+        s.parse()
+    }
+}
+
+impl AwsRegion {
+    /// Returns whether the service is available in the region.
+    // Taken from https://aws.amazon.com/about-aws/global-infrastructure/regional-product-services/
+    pub const fn supports(&self, service: Service) -> bool {
+        use AwsRegion::*;
+
+        match service {
+            Service::DynamoDb
+            | Service::DynamoDbGlobalTables
+            | Service::Kinesis
+            | Service::Lambda
+            | Service::S3
+            | Service::Sns
+            | Service::Sqs => true,
+            Service::AppRunner => matches!(
+                self,
+                ApNortheast1
+                    | ApSouth1
+                    | ApSoutheast1
+                    | ApSoutheast2
+                    | EuCentral1
+                    | EuWest1
+                    | EuWest2
+                    | EuWest3
+                    | UsEast1
+                    | UsEast2
+                    | UsWest2
+            ),
+            Service::Bedrock => matches!(
+                self,
+                ApNortheast1
+                    | ApNortheast2
+                    | ApSouth1
+                    | ApSoutheast1
+                    | ApSoutheast2
+                    | CaCentral1
+                    | EuCentral1
+                    | EuNorth1
+                    | EuWest1
+                    | EuWest2
+                    | EuWest3
+                    | SaEast1
+                    | UsEast1
+                    | UsEast2
+                    | UsWest2
+                    | UsGovWest1
+            ),
+            Service::Ses => !matches!(self, ApEast1 | CnNorth1 | CnNorthwest1 | MeCentral1 | UsGovEast1),
+        }
+    }
+}
+
+/// Finds the nearest AWS region that supports all of the given services, or
+/// `None` if no region does.
+pub fn find_region_supporting<L: IntoLatLon>(location: L, services: &[Service]) -> Option<AwsRegion> {
+    find_nearest_supporting(location, AwsRegion::iter(), services, AwsRegion::supports)
+}
+
+/// Finds the nearest AWS region from a list of regions that supports all of
+/// the given services, or `None` if no region does. Uses the embedded
+/// [`AwsRegion::supports`] data.
+pub fn find_region_from_list_supporting<L: IntoLatLon>(
+    location: L,
+    regions: &[AwsRegion],
+    services: &[Service],
+) -> Option<AwsRegion> {
+    find_region_from_list_supporting_with(location, regions, services, AwsRegion::supports)
+}
+
+/// Like [`find_region_from_list_supporting`], but takes the availability of
+/// services from the given function, e.g. to use more recent data.
+pub fn find_region_from_list_supporting_with<L, F>(
+    location: L,
+    regions: &[AwsRegion],
+    services: &[Service],
+    supports: F,
+) -> Option<AwsRegion>
+where
+    L: IntoLatLon,
+    F: Fn(&AwsRegion, Service) -> bool,
+{
+    find_nearest_supporting(location, regions.iter().copied(), services, supports)
+}
+
+fn find_nearest_supporting<L, I, F>(location: L, regions: I, services: &[Service], supports: F) -> Option<AwsRegion>
+where
+    L: IntoLatLon,
+    I: Iterator<Item = AwsRegion>,
+    F: Fn(&AwsRegion, Service) -> bool,
+{
+    let location = location.into_lat_lon();
+
+    regions
+        .filter(|region| services.iter().all(|service| supports(region, *service)))
+        .min_by_key(|region| ordered_float::OrderedFloat(region.distance_to(location)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const MUMBAI: (f64, f64) = (19.0760, 72.8777);
+    const HONG_KONG: (f64, f64) = (22.3193, 114.1694);
+
+    #[test]
+    fn test_service_from_str() {
+        assert_eq!("dynamodb".parse(), Ok(Service::DynamoDb));
+        assert_eq!("Bedrock".parse(), Ok(Service::Bedrock));
+        assert_eq!(Service::try_from("s3"), Ok(Service::S3));
+        assert_eq!("ec2".parse::<Service>(), Err(Error::InvalidService));
+        assert!(Service::iter().all(|service| service.to_string().parse() == Ok(service)));
+    }
+
+    #[test]
+    fn test_supports() {
+        assert!(AwsRegion::iter().all(|region| region.supports(Service::DynamoDb)));
+        assert!(AwsRegion::UsWest2.supports(Service::Bedrock));
+        assert!(!AwsRegion::ApEast1.supports(Service::Bedrock));
+        assert!(!AwsRegion::CnNorth1.supports(Service::AppRunner));
+        assert_eq!(Service::AppRunner.regions().count(), 11);
+        assert_eq!(Service::DynamoDb.regions().count(), AwsRegion::iter().count());
+    }
+
+    #[test]
+    fn test_find_region_supporting() {
+        struct Test {
+            location: (f64, f64),
+            services: Vec<Service>,
+            region: Option<AwsRegion>,
+        }
+
+        let tests = vec![
+            Test {
+                location: HONG_KONG,
+                services: vec![],
+                region: Some(AwsRegion::ApEast1),
+            },
+            Test {
+                location: HONG_KONG,
+                services: vec![Service::DynamoDb, Service::Sqs],
+                region: Some(AwsRegion::ApEast1),
+            },
+            Test {
+                location: HONG_KONG,
+                services: vec![Service::DynamoDb, Service::Bedrock],
+                region: Some(AwsRegion::ApNortheast2),
+            },
+            Test {
+                location: MUMBAI,
+                services: vec![Service::Bedrock, Service::AppRunner],
+                region: Some(AwsRegion::ApSouth1),
+            },
+        ];
+
+        for t in tests {
+            let region = find_region_supporting(t.location, &t.services);
+            assert_eq!(region, t.region, "{:?}", t.services);
+        }
+    }
+
+    #[test]
+    fn test_find_region_from_list_supporting() {
+        let list = [AwsRegion::ApEast1, AwsRegion::ApSoutheast1];
+        assert_eq!(
+            find_region_from_list_supporting(HONG_KONG, &list, &[Service::Bedrock]),
+            Some(AwsRegion::ApSoutheast1)
+        );
+        assert_eq!(
+            find_region_from_list_supporting(HONG_KONG, &list, &[Service::AppRunner, Service::Ses]),
+            Some(AwsRegion::ApSoutheast1)
+        );
+        assert_eq!(
+            find_region_from_list_supporting(HONG_KONG, &[AwsRegion::ApEast1], &[Service::Bedrock]),
+            None
+        );
+
+        // Bedrock launched in ap-east-1
+        let region = find_region_from_list_supporting_with(HONG_KONG, &list, &[Service::Bedrock], |region, service| {
+            region.supports(service) || *region == AwsRegion::ApEast1
+        });
+        assert_eq!(region, Some(AwsRegion::ApEast1));
+    }
+}
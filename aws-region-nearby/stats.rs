@@ -0,0 +1,39 @@
+/// Returns the weighted percentile (0-100) of the given values, using the
+/// nearest-rank method. Values with a weight of zero or less are ignored.
+pub fn weighted_percentile(values: &[f64], weights: &[f64], percentile: f64) -> f64 {
+    let mut pairs: Vec<(f64, f64)> = values
+        .iter()
+        .copied()
+        .zip(weights.iter().copied())
+        .filter(|(_, w)| *w > 0.0)
+        .collect();
+    pairs.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let total: f64 = pairs.iter().map(|(_, w)| w).sum();
+    let rank = total * percentile / 100.0;
+
+    let mut seen = 0.0;
+    for (value, weight) in &pairs {
+        seen += weight;
+        if seen >= rank {
+            return *value;
+        }
+    }
+    pairs.last().map_or(0.0, |(value, _)| *value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_weighted_percentile() {
+        let values = [1.0, 2.0, 3.0, 4.0];
+        assert_eq!(weighted_percentile(&values, &[1.0; 4], 50.0), 2.0);
+        assert_eq!(weighted_percentile(&values, &[1.0; 4], 95.0), 4.0);
+        assert_eq!(weighted_percentile(&values, &[1.0, 1.0, 1.0, 0.0], 100.0), 3.0);
+        assert_eq!(weighted_percentile(&values, &[10.0, 1.0, 1.0, 1.0], 50.0), 1.0);
+        assert_eq!(weighted_percentile(&[], &[], 50.0), 0.0);
+    }
+}
@@ -0,0 +1,188 @@
+use crate::aws::AwsRegion;
+use crate::latlon::IntoLatLon;
+
+/// How much closer another region must be before switching away from the
+/// previously chosen one.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SwitchMargin {
+    /// Absolute margin in meters.
+    Meters(f64),
+
+    /// Margin relative to the distance to the previous region, in percent.
+    Percent(f64),
+}
+
+impl SwitchMargin {
+    /// Returns the margin in meters for the given distance to the previous region.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the margin is negative or NaN.
+    pub fn meters(&self, previous_distance: f64) -> f64 {
+        self.assert_valid();
+
+        match *self {
+            Self::Meters(meters) => meters,
+            Self::Percent(percent) => previous_distance * percent / 100.0,
+        }
+    }
+
+    fn assert_valid(&self) {
+        let (Self::Meters(value) | Self::Percent(value)) = *self;
+        assert!(value >= 0.0, "margin must not be negative or NaN");
+    }
+}
+
+/// Finds the nearest AWS region from a list of regions, but sticks with the
+/// previously chosen region unless another one is closer by more than `margin`.
+///
+/// If the previous region is not in the list, the nearest region is returned.
+///
+/// # Panics
+///
+/// Panics if regions is empty or the margin is negative or NaN.
+pub fn find_sticky_region_from_list<L: IntoLatLon>(
+    location: L,
+    previous: AwsRegion,
+    regions: &[AwsRegion],
+    margin: SwitchMargin,
+) -> AwsRegion {
+    margin.assert_valid();
+
+    let location = location.into_lat_lon();
+    let nearest = crate::find_region_from_list(location, regions);
+
+    if nearest == previous || !regions.contains(&previous) {
+        return nearest;
+    }
+
+    let previous_distance = previous.distance_to(location);
+    let nearest_distance = nearest.distance_to(location);
+
+    if previous_distance - nearest_distance > margin.meters(previous_distance) {
+        nearest
+    } else {
+        previous
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_switch_margin_meters() {
+        assert_eq!(SwitchMargin::Meters(50_000.0).meters(300_000.0), 50_000.0);
+        assert_eq!(SwitchMargin::Percent(10.0).meters(300_000.0), 30_000.0);
+    }
+
+    #[test]
+    fn test_find_sticky_region_from_list() {
+        struct Test {
+            city: &'static str,
+            latitude: f64,
+            longitude: f64,
+            previous: AwsRegion,
+            margin: SwitchMargin,
+            region: AwsRegion,
+        }
+
+        let list = [AwsRegion::EuWest1, AwsRegion::EuWest2];
+
+        let tests = vec![
+            // About 13 km closer to London than to Dublin
+            Test {
+                city: "Manchester",
+                latitude: 53.4808,
+                longitude: -2.2426,
+                previous: AwsRegion::EuWest1,
+                margin: SwitchMargin::Meters(50_000.0),
+                region: AwsRegion::EuWest1,
+            },
+            Test {
+                city: "Manchester",
+                latitude: 53.4808,
+                longitude: -2.2426,
+                previous: AwsRegion::EuWest1,
+                margin: SwitchMargin::Meters(0.0),
+                region: AwsRegion::EuWest2,
+            },
+            Test {
+                city: "Manchester",
+                latitude: 53.4808,
+                longitude: -2.2426,
+                previous: AwsRegion::EuWest1,
+                margin: SwitchMargin::Percent(10.0),
+                region: AwsRegion::EuWest1,
+            },
+            Test {
+                city: "Manchester",
+                latitude: 53.4808,
+                longitude: -2.2426,
+                previous: AwsRegion::EuWest1,
+                margin: SwitchMargin::Percent(1.0),
+                region: AwsRegion::EuWest2,
+            },
+            Test {
+                city: "Manchester",
+                latitude: 53.4808,
+                longitude: -2.2426,
+                previous: AwsRegion::EuWest2,
+                margin: SwitchMargin::Meters(50_000.0),
+                region: AwsRegion::EuWest2,
+            },
+            Test {
+                city: "Holyhead",
+                latitude: 53.3094,
+                longitude: -4.6330,
+                previous: AwsRegion::EuWest2,
+                margin: SwitchMargin::Percent(25.0),
+                region: AwsRegion::EuWest1,
+            },
+            // Previous region is no longer a replica
+            Test {
+                city: "Manchester",
+                latitude: 53.4808,
+                longitude: -2.2426,
+                previous: AwsRegion::EuCentral1,
+                margin: SwitchMargin::Meters(1_000_000.0),
+                region: AwsRegion::EuWest2,
+            },
+        ];
+
+        for t in tests {
+            let region = find_sticky_region_from_list((t.latitude, t.longitude), t.previous, &list, t.margin);
+            assert_eq!(region, t.region, "{}", t.city);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "regions must not be empty")]
+    fn test_find_sticky_region_from_empty_list() {
+        find_sticky_region_from_list((0, 0), AwsRegion::EuWest1, &[], SwitchMargin::Meters(0.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "margin must not be negative or NaN")]
+    fn test_find_sticky_region_with_negative_margin() {
+        find_sticky_region_from_list(
+            (0, 0),
+            AwsRegion::EuWest1,
+            &[AwsRegion::EuWest1],
+            SwitchMargin::Meters(-1.0),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "margin must not be negative or NaN")]
+    fn test_find_sticky_region_with_nan_margin() {
+        find_sticky_region_from_list(
+            (0, 0),
+            AwsRegion::EuWest1,
+            &[AwsRegion::EuWest1],
+            SwitchMargin::Percent(f64::NAN),
+        );
+    }
+}
@@ -0,0 +1,578 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::aws::{find_region_from_list, AwsRegion};
+use crate::country::{find_region_for_country, Country};
+use crate::latlon::LatLon;
+use crate::Error;
+
+/// An ISO 3166-2 subdivision of a country, e.g. a US state.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Subdivision {
+    code: &'static str,
+    name: &'static str,
+    latitude: f64,
+    longitude: f64,
+    population: f64,
+}
+
+impl Subdivision {
+    /// Returns an iterator over all subdivisions.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        SUBDIVISIONS
+            .iter()
+            .flat_map(|subdivisions| subdivisions.iter().copied())
+    }
+
+    /// Returns the ISO 3166-2 code of the subdivision, e.g. `US-CA`.
+    pub const fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Returns the English name of the subdivision.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the population-weighted centroid of the subdivision.
+    pub const fn location(&self) -> LatLon {
+        LatLon::new(self.latitude, self.longitude)
+    }
+
+    /// Returns the approximate population of the subdivision, in millions.
+    pub const fn population(&self) -> f64 {
+        self.population
+    }
+
+    /// Returns the country of the subdivision.
+    pub fn country(&self) -> Country {
+        self.code[..2].parse().expect("subdivision of a known country")
+    }
+
+    /// Finds the nearest AWS region from a list of regions to the subdivision.
+    ///
+    /// # Panics
+    ///
+    /// Panics if regions is empty.
+    pub fn find_region_from_list(&self, regions: &[AwsRegion]) -> AwsRegion {
+        find_region_from_list(self.location(), regions)
+    }
+}
+
+impl fmt::Display for Subdivision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code)
+    }
+}
+
+impl FromStr for Subdivision {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Self::iter()
+            .find(|subdivision| subdivision.code.eq_ignore_ascii_case(s))
+            .ok_or(Error::InvalidSubdivision)
+    }
+}
+
+impl TryFrom<&str> for Subdivision {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Error> {
+        s.parse()
+    }
+}
+
+impl Country {
+    /// Returns the subdivisions of the country with their population.
+    ///
+    /// Only available for countries whose population is spread over several
+    /// AWS regions (AU, BR, CA, CN, GB, IN, JP, MX and US); empty for all
+    /// others.
+    pub fn subdivisions(&self) -> &'static [Subdivision] {
+        match self.code() {
+            "AU" => &AU_SUBDIVISIONS,
+            "BR" => &BR_SUBDIVISIONS,
+            "CA" => &CA_SUBDIVISIONS,
+            "CN" => &CN_SUBDIVISIONS,
+            "GB" => &GB_SUBDIVISIONS,
+            "IN" => &IN_SUBDIVISIONS,
+            "JP" => &JP_SUBDIVISIONS,
+            "MX" => &MX_SUBDIVISIONS,
+            "US" => &US_SUBDIVISIONS,
+            _ => &[],
+        }
+    }
+}
+
+/// Finds the nearest AWS region from a list of regions to a subdivision, given
+/// its ISO 3166-2 code (case-insensitive), e.g. `US-CA`.
+///
+/// Subdivisions that are not known fall back to their country, see
+/// [`find_region_for_country`]. Fails if the code is malformed or the country
+/// is not known.
+///
+/// # Panics
+///
+/// Panics if regions is empty.
+pub fn find_region_for_subdivision(code: &str, regions: &[AwsRegion]) -> Result<AwsRegion, Error> {
+    if let Ok(subdivision) = code.parse::<Subdivision>() {
+        return Ok(subdivision.find_region_from_list(regions));
+    }
+
+    let (country, subdivision) = code.split_once('-').ok_or(Error::InvalidSubdivision)?;
+    if !(1..=3).contains(&subdivision.len()) || !subdivision.bytes().all(|b| b.is_ascii_alphanumeric()) {
+        return Err(Error::InvalidSubdivision);
+    }
+    find_region_for_country(country, regions)
+}
+
+const fn subdivision(
+    code: &'static str,
+    name: &'static str,
+    latitude: f64,
+    longitude: f64,
+    population: f64,
+) -> Subdivision {
+    Subdivision {
+        code,
+        name,
+        latitude,
+        longitude,
+        population,
+    }
+}
+
+const SUBDIVISIONS: [&[Subdivision]; 9] = [
+    &AU_SUBDIVISIONS,
+    &BR_SUBDIVISIONS,
+    &CA_SUBDIVISIONS,
+    &CN_SUBDIVISIONS,
+    &GB_SUBDIVISIONS,
+    &IN_SUBDIVISIONS,
+    &JP_SUBDIVISIONS,
+    &MX_SUBDIVISIONS,
+    &US_SUBDIVISIONS,
+];
+
+const AU_SUBDIVISIONS: [Subdivision; 8] = [
+    subdivision("AU-ACT", "Australian Capital Territory", -35.3, 149.1, 0.45),
+    subdivision("AU-NSW", "New South Wales", -33.3, 150.6, 8.2),
+    subdivision("AU-NT", "Northern Territory", -13.5, 131.5, 0.25),
+    subdivision("AU-QLD", "Queensland", -25.6, 151.9, 5.2),
+    subdivision("AU-SA", "South Australia", -34.8, 138.6, 1.8),
+    subdivision("AU-TAS", "Tasmania", -42.0, 147.0, 0.57),
+    subdivision("AU-VIC", "Victoria", -37.7, 144.8, 6.6),
+    subdivision("AU-WA", "Western Australia", -31.9, 116.0, 2.7),
+];
+
+const BR_SUBDIVISIONS: [Subdivision; 27] = [
+    subdivision("BR-AC", "Acre", -9.6, -68.6, 0.9),
+    subdivision("BR-AL", "Alagoas", -9.6, -36.2, 3.4),
+    subdivision("BR-AM", "Amazonas", -3.3, -60.2, 4.2),
+    subdivision("BR-AP", "Amapá", 0.2, -51.3, 0.9),
+    subdivision("BR-BA", "Bahia", -12.8, -39.6, 15.0),
+    subdivision("BR-CE", "Ceará", -4.3, -39.2, 9.2),
+    subdivision("BR-DF", "Distrito Federal", -15.8, -47.9, 3.1),
+    subdivision("BR-ES", "Espírito Santo", -20.2, -40.5, 4.1),
+    subdivision("BR-GO", "Goiás", -16.4, -49.4, 7.1),
+    subdivision("BR-MA", "Maranhão", -3.9, -44.6, 7.1),
+    subdivision("BR-MG", "Minas Gerais", -19.7, -44.3, 21.0),
+    subdivision("BR-MS", "Mato Grosso do Sul", -20.8, -54.7, 2.8),
+    subdivision("BR-MT", "Mato Grosso", -15.0, -55.8, 3.5),
+    subdivision("BR-PA", "Pará", -2.5, -49.2, 8.7),
+    subdivision("BR-PB", "Paraíba", -7.2, -35.7, 4.0),
+    subdivision("BR-PE", "Pernambuco", -8.2, -35.9, 9.6),
+    subdivision("BR-PI", "Piauí", -5.6, -42.5, 3.3),
+    subdivision("BR-PR", "Paraná", -24.8, -50.8, 11.5),
+    subdivision("BR-RJ", "Rio de Janeiro", -22.8, -43.2, 17.0),
+    subdivision("BR-RN", "Rio Grande do Norte", -5.8, -35.9, 3.5),
+    subdivision("BR-RO", "Rondônia", -10.0, -63.0, 1.8),
+    subdivision("BR-RR", "Roraima", 2.7, -60.8, 0.6),
+    subdivision("BR-RS", "Rio Grande do Sul", -29.8, -51.9, 11.4),
+    subdivision("BR-SC", "Santa Catarina", -27.3, -49.5, 7.3),
+    subdivision("BR-SE", "Sergipe", -10.8, -37.2, 2.3),
+    subdivision("BR-SP", "São Paulo", -23.2, -47.4, 46.0),
+    subdivision("BR-TO", "Tocantins", -10.0, -48.4, 1.6),
+];
+
+const CA_SUBDIVISIONS: [Subdivision; 13] = [
+    subdivision("CA-AB", "Alberta", 52.0, -113.9, 4.3),
+    subdivision("CA-BC", "British Columbia", 49.5, -122.9, 5.0),
+    subdivision("CA-MB", "Manitoba", 50.2, -97.6, 1.4),
+    subdivision("CA-NB", "New Brunswick", 46.2, -66.0, 0.8),
+    subdivision("CA-NL", "Newfoundland and Labrador", 48.0, -54.9, 0.5),
+    subdivision("CA-NS", "Nova Scotia", 45.0, -63.6, 1.0),
+    subdivision("CA-NT", "Northwest Territories", 62.4, -114.4, 0.045),
+    subdivision("CA-NU", "Nunavut", 64.0, -76.0, 0.039),
+    subdivision("CA-ON", "Ontario", 43.9, -79.4, 14.2),
+    subdivision("CA-PE", "Prince Edward Island", 46.3, -63.2, 0.16),
+    subdivision("CA-QC", "Quebec", 46.0, -72.9, 8.5),
+    subdivision("CA-SK", "Saskatchewan", 51.5, -105.8, 1.2),
+    subdivision("CA-YT", "Yukon", 60.7, -135.1, 0.043),
+];
+
+// Hong Kong, Macao and Taiwan have their own ISO 3166-1 codes
+const CN_SUBDIVISIONS: [Subdivision; 31] = [
+    subdivision("CN-AH", "Anhui", 31.9, 117.2, 61.0),
+    subdivision("CN-BJ", "Beijing", 39.9, 116.4, 22.0),
+    subdivision("CN-CQ", "Chongqing", 29.8, 106.8, 32.0),
+    subdivision("CN-FJ", "Fujian", 25.5, 118.6, 41.0),
+    subdivision("CN-GD", "Guangdong", 23.1, 113.3, 126.0),
+    subdivision("CN-GS", "Gansu", 36.0, 104.5, 25.0),
+    subdivision("CN-GX", "Guangxi", 23.2, 109.2, 50.0),
+    subdivision("CN-GZ", "Guizhou", 26.6, 106.7, 39.0),
+    subdivision("CN-HA", "Henan", 34.3, 113.6, 99.0),
+    subdivision("CN-HB", "Hubei", 30.7, 113.2, 58.0),
+    subdivision("CN-HE", "Hebei", 38.3, 115.3, 75.0),
+    subdivision("CN-HI", "Hainan", 19.6, 110.0, 10.0),
+    subdivision("CN-HL", "Heilongjiang", 46.2, 127.4, 32.0),
+    subdivision("CN-HN", "Hunan", 27.7, 112.1, 66.0),
+    subdivision("CN-JL", "Jilin", 43.7, 125.8, 24.0),
+    subdivision("CN-JS", "Jiangsu", 32.4, 119.5, 85.0),
+    subdivision("CN-JX", "Jiangxi", 27.9, 115.7, 45.0),
+    subdivision("CN-LN", "Liaoning", 41.5, 122.8, 43.0),
+    subdivision("CN-NM", "Inner Mongolia", 41.3, 112.0, 24.0),
+    subdivision("CN-NX", "Ningxia", 37.9, 106.2, 7.2),
+    subdivision("CN-QH", "Qinghai", 36.5, 101.5, 5.9),
+    subdivision("CN-SC", "Sichuan", 30.4, 104.5, 84.0),
+    subdivision("CN-SD", "Shandong", 36.3, 117.9, 102.0),
+    subdivision("CN-SH", "Shanghai", 31.2, 121.5, 25.0),
+    subdivision("CN-SN", "Shaanxi", 34.4, 108.7, 40.0),
+    subdivision("CN-SX", "Shanxi", 37.6, 112.4, 35.0),
+    subdivision("CN-TJ", "Tianjin", 39.1, 117.2, 14.0),
+    subdivision("CN-XJ", "Xinjiang", 42.0, 84.0, 26.0),
+    subdivision("CN-XZ", "Tibet", 29.6, 91.1, 3.6),
+    subdivision("CN-YN", "Yunnan", 24.9, 102.3, 47.0),
+    subdivision("CN-ZJ", "Zhejiang", 29.6, 120.4, 65.0),
+];
+
+const GB_SUBDIVISIONS: [Subdivision; 4] = [
+    subdivision("GB-ENG", "England", 52.6, -1.5, 56.5),
+    subdivision("GB-NIR", "Northern Ireland", 54.6, -6.2, 1.9),
+    subdivision("GB-SCT", "Scotland", 56.0, -3.8, 5.5),
+    subdivision("GB-WLS", "Wales", 51.8, -3.4, 3.1),
+];
+
+// Without the smallest union territories
+const IN_SUBDIVISIONS: [Subdivision; 32] = [
+    subdivision("IN-AP", "Andhra Pradesh", 15.9, 80.0, 49.0),
+    subdivision("IN-AR", "Arunachal Pradesh", 27.6, 93.6, 1.4),
+    subdivision("IN-AS", "Assam", 26.3, 92.5, 31.0),
+    subdivision("IN-BR", "Bihar", 25.8, 85.8, 104.0),
+    subdivision("IN-CH", "Chandigarh", 30.7, 76.8, 1.1),
+    subdivision("IN-CT", "Chhattisgarh", 21.3, 81.8, 26.0),
+    subdivision("IN-DL", "Delhi", 28.65, 77.2, 17.0),
+    subdivision("IN-GA", "Goa", 15.4, 74.0, 1.5),
+    subdivision("IN-GJ", "Gujarat", 22.5, 72.0, 60.0),
+    subdivision("IN-HP", "Himachal Pradesh", 31.6, 76.9, 6.9),
+    subdivision("IN-HR", "Haryana", 29.1, 76.6, 25.0),
+    subdivision("IN-JH", "Jharkhand", 23.6, 85.5, 33.0),
+    subdivision("IN-JK", "Jammu and Kashmir", 33.6, 75.0, 12.5),
+    subdivision("IN-KA", "Karnataka", 14.3, 76.2, 61.0),
+    subdivision("IN-KL", "Kerala", 10.2, 76.3, 33.0),
+    subdivision("IN-MH", "Maharashtra", 19.3, 75.3, 112.0),
+    subdivision("IN-ML", "Meghalaya", 25.5, 91.6, 3.0),
+    subdivision("IN-MN", "Manipur", 24.7, 93.9, 2.9),
+    subdivision("IN-MP", "Madhya Pradesh", 23.4, 78.0, 73.0),
+    subdivision("IN-MZ", "Mizoram", 23.5, 92.8, 1.1),
+    subdivision("IN-NL", "Nagaland", 26.0, 94.2, 2.0),
+    subdivision("IN-OR", "Odisha", 20.5, 84.7, 42.0),
+    subdivision("IN-PB", "Punjab", 30.9, 75.4, 28.0),
+    subdivision("IN-PY", "Puducherry", 11.9, 79.8, 1.2),
+    subdivision("IN-RJ", "Rajasthan", 26.6, 74.6, 69.0),
+    subdivision("IN-SK", "Sikkim", 27.3, 88.5, 0.6),
+    subdivision("IN-TG", "Telangana", 17.6, 78.8, 35.0),
+    subdivision("IN-TN", "Tamil Nadu", 11.3, 78.7, 72.0),
+    subdivision("IN-TR", "Tripura", 23.8, 91.3, 3.7),
+    subdivision("IN-UP", "Uttar Pradesh", 26.9, 80.9, 200.0),
+    subdivision("IN-UT", "Uttarakhand", 29.9, 78.4, 10.0),
+    subdivision("IN-WB", "West Bengal", 23.3, 88.1, 91.0),
+];
+
+const JP_SUBDIVISIONS: [Subdivision; 47] = [
+    subdivision("JP-01", "Hokkaido", 43.06, 141.35, 5.2),
+    subdivision("JP-02", "Aomori", 40.82, 140.74, 1.2),
+    subdivision("JP-03", "Iwate", 39.7, 141.15, 1.2),
+    subdivision("JP-04", "Miyagi", 38.27, 140.87, 2.3),
+    subdivision("JP-05", "Akita", 39.72, 140.1, 0.96),
+    subdivision("JP-06", "Yamagata", 38.24, 140.36, 1.07),
+    subdivision("JP-07", "Fukushima", 37.4, 140.47, 1.8),
+    subdivision("JP-08", "Ibaraki", 36.34, 140.45, 2.87),
+    subdivision("JP-09", "Tochigi", 36.57, 139.88, 1.93),
+    subdivision("JP-10", "Gunma", 36.39, 139.06, 1.94),
+    subdivision("JP-11", "Saitama", 35.86, 139.65, 7.34),
+    subdivision("JP-12", "Chiba", 35.61, 140.12, 6.3),
+    subdivision("JP-13", "Tokyo", 35.69, 139.69, 14.0),
+    subdivision("JP-14", "Kanagawa", 35.45, 139.64, 9.24),
+    subdivision("JP-15", "Niigata", 37.9, 139.02, 2.2),
+    subdivision("JP-16", "Toyama", 36.7, 137.21, 1.03),
+    subdivision("JP-17", "Ishikawa", 36.59, 136.63, 1.13),
+    subdivision("JP-18", "Fukui", 36.07, 136.22, 0.77),
+    subdivision("JP-19", "Yamanashi", 35.66, 138.57, 0.81),
+    subdivision("JP-20", "Nagano", 36.65, 138.18, 2.05),
+    subdivision("JP-21", "Gifu", 35.39, 136.72, 1.98),
+    subdivision("JP-22", "Shizuoka", 34.98, 138.38, 3.63),
+    subdivision("JP-23", "Aichi", 35.18, 136.91, 7.5),
+    subdivision("JP-24", "Mie", 34.73, 136.51, 1.77),
+    subdivision("JP-25", "Shiga", 35.0, 135.87, 1.41),
+    subdivision("JP-26", "Kyoto", 35.02, 135.76, 2.58),
+    subdivision("JP-27", "Osaka", 34.69, 135.52, 8.84),
+    subdivision("JP-28", "Hyogo", 34.69, 135.18, 5.47),
+    subdivision("JP-29", "Nara", 34.69, 135.83, 1.32),
+    subdivision("JP-30", "Wakayama", 34.23, 135.17, 0.92),
+    subdivision("JP-31", "Tottori", 35.5, 134.24, 0.55),
+    subdivision("JP-32", "Shimane", 35.47, 133.05, 0.67),
+    subdivision("JP-33", "Okayama", 34.66, 133.93, 1.89),
+    subdivision("JP-34", "Hiroshima", 34.4, 132.46, 2.8),
+    subdivision("JP-35", "Yamaguchi", 34.19, 131.47, 1.34),
+    subdivision("JP-36", "Tokushima", 34.07, 134.56, 0.72),
+    subdivision("JP-37", "Kagawa", 34.34, 134.04, 0.95),
+    subdivision("JP-38", "Ehime", 33.84, 132.77, 1.33),
+    subdivision("JP-39", "Kochi", 33.56, 133.53, 0.69),
+    subdivision("JP-40", "Fukuoka", 33.61, 130.42, 5.14),
+    subdivision("JP-41", "Saga", 33.25, 130.3, 0.81),
+    subdivision("JP-42", "Nagasaki", 32.74, 129.87, 1.31),
+    subdivision("JP-43", "Kumamoto", 32.79, 130.74, 1.74),
+    subdivision("JP-44", "Oita", 33.24, 131.61, 1.12),
+    subdivision("JP-45", "Miyazaki", 31.91, 131.42, 1.07),
+    subdivision("JP-46", "Kagoshima", 31.56, 130.56, 1.59),
+    subdivision("JP-47", "Okinawa", 26.21, 127.68, 1.47),
+];
+
+const MX_SUBDIVISIONS: [Subdivision; 32] = [
+    subdivision("MX-AGU", "Aguascalientes", 21.88, -102.29, 1.43),
+    subdivision("MX-BCN", "Baja California", 32.5, -116.6, 3.77),
+    subdivision("MX-BCS", "Baja California Sur", 23.6, -110.5, 0.8),
+    subdivision("MX-CAM", "Campeche", 19.3, -90.7, 0.93),
+    subdivision("MX-CHH", "Chihuahua", 30.0, -106.4, 3.74),
+    subdivision("MX-CHP", "Chiapas", 16.5, -92.8, 5.54),
+    subdivision("MX-CMX", "Mexico City", 19.43, -99.13, 9.2),
+    subdivision("MX-COA", "Coahuila", 26.5, -101.6, 3.15),
+    subdivision("MX-COL", "Colima", 19.1, -103.9, 0.73),
+    subdivision("MX-DUR", "Durango", 24.8, -104.4, 1.83),
+    subdivision("MX-GRO", "Guerrero", 17.3, -99.7, 3.54),
+    subdivision("MX-GUA", "Guanajuato", 20.8, -101.2, 6.17),
+    subdivision("MX-HID", "Hidalgo", 20.2, -98.8, 3.08),
+    subdivision("MX-JAL", "Jalisco", 20.6, -103.3, 8.35),
+    subdivision("MX-MEX", "México", 19.4, -99.3, 16.99),
+    subdivision("MX-MIC", "Michoacán", 19.6, -101.7, 4.75),
+    subdivision("MX-MOR", "Morelos", 18.8, -99.1, 1.97),
+    subdivision("MX-NAY", "Nayarit", 21.5, -104.9, 1.24),
+    subdivision("MX-NLE", "Nuevo León", 25.6, -100.3, 5.78),
+    subdivision("MX-OAX", "Oaxaca", 17.1, -96.7, 4.13),
+    subdivision("MX-PUE", "Puebla", 19.0, -98.0, 6.58),
+    subdivision("MX-QUE", "Querétaro", 20.6, -100.2, 2.37),
+    subdivision("MX-ROO", "Quintana Roo", 20.6, -87.2, 1.86),
+    subdivision("MX-SIN", "Sinaloa", 24.8, -107.4, 3.03),
+    subdivision("MX-SLP", "San Luis Potosí", 22.2, -100.6, 2.82),
+    subdivision("MX-SON", "Sonora", 29.5, -110.9, 2.94),
+    subdivision("MX-TAB", "Tabasco", 18.0, -92.9, 2.4),
+    subdivision("MX-TAM", "Tamaulipas", 24.5, -98.5, 3.53),
+    subdivision("MX-TLA", "Tlaxcala", 19.4, -98.2, 1.34),
+    subdivision("MX-VER", "Veracruz", 19.3, -96.7, 8.06),
+    subdivision("MX-YUC", "Yucatán", 20.9, -89.6, 2.32),
+    subdivision("MX-ZAC", "Zacatecas", 22.9, -102.6, 1.62),
+];
+
+const US_SUBDIVISIONS: [Subdivision; 51] = [
+    subdivision("US-AL", "Alabama", 33.0, -86.76, 5.02),
+    subdivision("US-AK", "Alaska", 61.3, -149.4, 0.73),
+    subdivision("US-AZ", "Arizona", 33.4, -111.9, 7.15),
+    subdivision("US-AR", "Arkansas", 35.0, -92.4, 3.01),
+    subdivision("US-CA", "California", 35.5, -119.4, 39.5),
+    subdivision("US-CO", "Colorado", 39.5, -105.0, 5.77),
+    subdivision("US-CT", "Connecticut", 41.5, -72.9, 3.61),
+    subdivision("US-DE", "Delaware", 39.4, -75.6, 0.99),
+    subdivision("US-DC", "District of Columbia", 38.9, -77.0, 0.69),
+    subdivision("US-FL", "Florida", 27.8, -81.6, 21.5),
+    subdivision("US-GA", "Georgia", 33.4, -84.1, 10.7),
+    subdivision("US-HI", "Hawaii", 21.3, -157.8, 1.46),
+    subdivision("US-ID", "Idaho", 43.8, -115.9, 1.84),
+    subdivision("US-IL", "Illinois", 41.3, -88.4, 12.8),
+    subdivision("US-IN", "Indiana", 39.9, -86.3, 6.79),
+    subdivision("US-IA", "Iowa", 41.9, -93.0, 3.19),
+    subdivision("US-KS", "Kansas", 38.4, -96.6, 2.94),
+    subdivision("US-KY", "Kentucky", 37.8, -85.3, 4.51),
+    subdivision("US-LA", "Louisiana", 30.7, -91.5, 4.66),
+    subdivision("US-ME", "Maine", 44.2, -69.9, 1.36),
+    subdivision("US-MD", "Maryland", 39.1, -76.8, 6.18),
+    subdivision("US-MA", "Massachusetts", 42.3, -71.4, 7.03),
+    subdivision("US-MI", "Michigan", 42.9, -84.2, 10.1),
+    subdivision("US-MN", "Minnesota", 45.2, -93.9, 5.71),
+    subdivision("US-MS", "Mississippi", 32.6, -89.6, 2.96),
+    subdivision("US-MO", "Missouri", 38.4, -92.2, 6.15),
+    subdivision("US-MT", "Montana", 46.6, -110.9, 1.08),
+    subdivision("US-NE", "Nebraska", 41.2, -97.4, 1.96),
+    subdivision("US-NV", "Nevada", 36.4, -115.6, 3.1),
+    subdivision("US-NH", "New Hampshire", 43.1, -71.5, 1.38),
+    subdivision("US-NJ", "New Jersey", 40.4, -74.4, 9.29),
+    subdivision("US-NM", "New Mexico", 34.6, -106.3, 2.12),
+    subdivision("US-NY", "New York", 41.5, -74.6, 20.2),
+    subdivision("US-NC", "North Carolina", 35.6, -79.4, 10.4),
+    subdivision("US-ND", "North Dakota", 47.4, -99.0, 0.78),
+    subdivision("US-OH", "Ohio", 40.5, -82.7, 11.8),
+    subdivision("US-OK", "Oklahoma", 35.6, -96.8, 3.96),
+    subdivision("US-OR", "Oregon", 44.7, -122.6, 4.24),
+    subdivision("US-PA", "Pennsylvania", 40.5, -77.0, 13.0),
+    subdivision("US-RI", "Rhode Island", 41.8, -71.5, 1.1),
+    subdivision("US-SC", "South Carolina", 34.0, -81.0, 5.12),
+    subdivision("US-SD", "South Dakota", 44.1, -98.6, 0.89),
+    subdivision("US-TN", "Tennessee", 35.8, -86.4, 6.91),
+    subdivision("US-TX", "Texas", 30.9, -97.4, 29.1),
+    subdivision("US-UT", "Utah", 40.4, -111.9, 3.27),
+    subdivision("US-VT", "Vermont", 44.1, -72.8, 0.64),
+    subdivision("US-VA", "Virginia", 37.9, -77.8, 8.63),
+    subdivision("US-WA", "Washington", 47.3, -121.6, 7.71),
+    subdivision("US-WV", "West Virginia", 38.7, -80.9, 1.79),
+    subdivision("US-WI", "Wisconsin", 43.7, -89.0, 5.89),
+    subdivision("US-WY", "Wyoming", 42.6, -106.9, 0.58),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_country_subdivisions() {
+        struct Test {
+            country: &'static str,
+            count: usize,
+        }
+
+        let tests = vec![
+            Test {
+                country: "US",
+                count: 51,
+            },
+            Test {
+                country: "CA",
+                count: 13,
+            },
+            Test {
+                country: "AU",
+                count: 8,
+            },
+            Test {
+                country: "DE",
+                count: 0,
+            },
+        ];
+
+        for t in tests {
+            let country: Country = t.country.parse().unwrap();
+            let subdivisions = country.subdivisions();
+            assert_eq!(subdivisions.len(), t.count, "{}", t.country);
+            assert!(
+                subdivisions
+                    .iter()
+                    .all(|s| s.code().starts_with(&format!("{}-", t.country))),
+                "{}",
+                t.country
+            );
+        }
+
+        let california = Country::try_from("US").unwrap().subdivisions()[4];
+        assert_eq!(california.code(), "US-CA");
+        assert_eq!(california.name(), "California");
+    }
+
+    #[test]
+    fn test_subdivision_from_str() {
+        let subdivision: Subdivision = "ca-on".parse().unwrap();
+        assert_eq!(subdivision.code(), "CA-ON");
+        assert_eq!(subdivision.name(), "Ontario");
+        assert_eq!(subdivision.to_string(), "CA-ON");
+        assert_eq!(subdivision.country().code(), "CA");
+
+        assert_eq!(Subdivision::try_from("JP-27").unwrap().name(), "Osaka");
+        assert_eq!("US-XX".parse::<Subdivision>(), Err(Error::InvalidSubdivision));
+        assert_eq!("US".parse::<Subdivision>(), Err(Error::InvalidSubdivision));
+    }
+
+    #[test]
+    fn test_subdivision_iter() {
+        assert_eq!(Subdivision::iter().count(), 245);
+        assert!(Subdivision::iter().all(|subdivision| subdivision.country().subdivisions().contains(&subdivision)));
+    }
+
+    #[test]
+    fn test_find_region_for_subdivision() {
+        struct Test {
+            code: &'static str,
+            list: Vec<AwsRegion>,
+            region: Result<AwsRegion, Error>,
+        }
+
+        let us = vec![
+            AwsRegion::UsEast1,
+            AwsRegion::UsEast2,
+            AwsRegion::UsWest1,
+            AwsRegion::UsWest2,
+        ];
+        let tests = vec![
+            Test {
+                code: "US-CA",
+                list: us.clone(),
+                region: Ok(AwsRegion::UsWest1),
+            },
+            Test {
+                code: "us-wa",
+                list: us.clone(),
+                region: Ok(AwsRegion::UsWest2),
+            },
+            Test {
+                code: "US-MI",
+                list: us.clone(),
+                region: Ok(AwsRegion::UsEast2),
+            },
+            Test {
+                code: "CA-BC",
+                list: vec![AwsRegion::CaCentral1, AwsRegion::UsWest2],
+                region: Ok(AwsRegion::UsWest2),
+            },
+            Test {
+                code: "AU-WA",
+                list: vec![AwsRegion::ApSoutheast2, AwsRegion::ApSoutheast3],
+                region: Ok(AwsRegion::ApSoutheast3),
+            },
+            Test {
+                code: "GB-NIR",
+                list: vec![AwsRegion::EuWest1, AwsRegion::EuWest2],
+                region: Ok(AwsRegion::EuWest1),
+            },
+            Test {
+                code: "JP-27",
+                list: vec![AwsRegion::ApNortheast1, AwsRegion::ApNortheast3],
+                region: Ok(AwsRegion::ApNortheast3),
+            },
+            // Unknown subdivisions fall back to the country
+            Test {
+                code: "DE-BY",
+                list: vec![AwsRegion::EuWest1, AwsRegion::EuCentral1],
+                region: Ok(AwsRegion::EuCentral1),
+            },
+            Test {
+                code: "ZZ-AB",
+                list: us.clone(),
+                region: Err(Error::InvalidCountry),
+            },
+            Test {
+                code: "DE",
+                list: us.clone(),
+                region: Err(Error::InvalidSubdivision),
+            },
+            Test {
+                code: "DE-BAYERN",
+                list: us,
+                region: Err(Error::InvalidSubdivision),
+            },
+        ];
+
+        for t in tests {
+            let region = find_region_for_subdivision(t.code, &t.list);
+            assert_eq!(region, t.region, "{}", t.code);
+        }
+    }
+}
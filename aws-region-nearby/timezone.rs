@@ -0,0 +1,723 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::aws::{find_region_from_list, AwsRegion};
+use crate::latlon::LatLon;
+use crate::Error;
+
+/// An IANA time zone, e.g. `Europe/Berlin`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Timezone {
+    name: &'static str,
+    latitude: f64,
+    longitude: f64,
+}
+
+impl Timezone {
+    /// Returns an iterator over all canonical time zones.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        TIMEZONES.iter().copied()
+    }
+
+    /// Returns the canonical name of the time zone.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the location of the principal city of the time zone.
+    pub const fn location(&self) -> LatLon {
+        LatLon::new(self.latitude, self.longitude)
+    }
+
+    /// Finds the nearest AWS region from a list of regions to the time zone.
+    ///
+    /// # Panics
+    ///
+    /// Panics if regions is empty.
+    pub fn find_region_from_list(&self, regions: &[AwsRegion]) -> AwsRegion {
+        find_region_from_list(self.location(), regions)
+    }
+}
+
+impl fmt::Display for Timezone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+impl FromStr for Timezone {
+    type Err = Error;
+
+    /// Parses a time zone name case-insensitively. Deprecated names that are
+    /// still reported by browsers, e.g. `Asia/Calcutta`, resolve to their
+    /// canonical zone.
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let name = TIMEZONE_ALIASES
+            .iter()
+            .find(|(alias, _)| alias.eq_ignore_ascii_case(s))
+            .map_or(s, |(_, name)| name);
+
+        TIMEZONES
+            .iter()
+            .find(|timezone| timezone.name.eq_ignore_ascii_case(name))
+            .copied()
+            .ok_or(Error::InvalidTimezone)
+    }
+}
+
+impl TryFrom<&str> for Timezone {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Error> {
+        s.parse()
+    }
+}
+
+/// Finds the nearest AWS region from a list of regions to an IANA time zone,
+/// e.g. the result of `Intl.DateTimeFormat().resolvedOptions().timeZone` in a
+/// browser.
+///
+/// Zones without a location, such as `UTC` or `Etc/GMT+2`, are rejected.
+///
+/// # Panics
+///
+/// Panics if regions is empty.
+pub fn find_region_for_timezone(name: &str, regions: &[AwsRegion]) -> Result<AwsRegion, Error> {
+    let timezone: Timezone = name.parse()?;
+    Ok(timezone.find_region_from_list(regions))
+}
+
+const fn timezone(name: &'static str, latitude: f64, longitude: f64) -> Timezone {
+    Timezone {
+        name,
+        latitude,
+        longitude,
+    }
+}
+
+/// Canonical time zones with the location of their principal city. Based on
+/// `zone.tab` of the tz database.
+const TIMEZONES: [Timezone; 416] = [
+    timezone("Africa/Abidjan", 5.3167, -4.0333),
+    timezone("Africa/Accra", 5.55, -0.2167),
+    timezone("Africa/Addis_Ababa", 9.0333, 38.7),
+    timezone("Africa/Algiers", 36.7833, 3.05),
+    timezone("Africa/Asmara", 15.3333, 38.8833),
+    timezone("Africa/Bamako", 12.65, -8.0),
+    timezone("Africa/Bangui", 4.3667, 18.5833),
+    timezone("Africa/Banjul", 13.4667, -16.65),
+    timezone("Africa/Bissau", 11.85, -15.5833),
+    timezone("Africa/Blantyre", -15.7833, 35.0),
+    timezone("Africa/Brazzaville", -4.2667, 15.2833),
+    timezone("Africa/Bujumbura", -3.3833, 29.3667),
+    timezone("Africa/Cairo", 30.05, 31.25),
+    timezone("Africa/Casablanca", 33.65, -7.5833),
+    timezone("Africa/Ceuta", 35.8833, -5.3167),
+    timezone("Africa/Conakry", 9.5167, -13.7167),
+    timezone("Africa/Dakar", 14.6667, -17.4333),
+    timezone("Africa/Dar_es_Salaam", -6.8, 39.2833),
+    timezone("Africa/Djibouti", 11.6, 43.15),
+    timezone("Africa/Douala", 4.05, 9.7),
+    timezone("Africa/El_Aaiun", 27.15, -13.2),
+    timezone("Africa/Freetown", 8.5, -13.25),
+    timezone("Africa/Gaborone", -24.65, 25.9167),
+    timezone("Africa/Harare", -17.8333, 31.05),
+    timezone("Africa/Johannesburg", -26.25, 28.0),
+    timezone("Africa/Juba", 4.85, 31.6167),
+    timezone("Africa/Kampala", 0.3167, 32.4167),
+    timezone("Africa/Khartoum", 15.6, 32.5333),
+    timezone("Africa/Kigali", -1.95, 30.0667),
+    timezone("Africa/Kinshasa", -4.3, 15.3),
+    timezone("Africa/Lagos", 6.45, 3.4),
+    timezone("Africa/Libreville", 0.3833, 9.45),
+    timezone("Africa/Lome", 6.1333, 1.2167),
+    timezone("Africa/Luanda", -8.8, 13.2333),
+    timezone("Africa/Lubumbashi", -11.6667, 27.4667),
+    timezone("Africa/Lusaka", -15.4167, 28.2833),
+    timezone("Africa/Malabo", 3.75, 8.7833),
+    timezone("Africa/Maputo", -25.9667, 32.5833),
+    timezone("Africa/Maseru", -29.4667, 27.5),
+    timezone("Africa/Mbabane", -26.3, 31.1),
+    timezone("Africa/Mogadishu", 2.0667, 45.3667),
+    timezone("Africa/Monrovia", 6.3, -10.7833),
+    timezone("Africa/Nairobi", -1.2833, 36.8167),
+    timezone("Africa/Ndjamena", 12.1167, 15.05),
+    timezone("Africa/Niamey", 13.5167, 2.1167),
+    timezone("Africa/Nouakchott", 18.1, -15.95),
+    timezone("Africa/Ouagadougou", 12.3667, -1.5167),
+    timezone("Africa/Porto-Novo", 6.4833, 2.6167),
+    timezone("Africa/Sao_Tome", 0.3333, 6.7333),
+    timezone("Africa/Tripoli", 32.9, 13.1833),
+    timezone("Africa/Tunis", 36.8, 10.1833),
+    timezone("Africa/Windhoek", -22.5667, 17.1),
+    timezone("America/Adak", 51.88, -176.6581),
+    timezone("America/Anchorage", 61.2181, -149.9003),
+    timezone("America/Anguilla", 18.2, -63.0667),
+    timezone("America/Antigua", 17.1167, -61.85),
+    timezone("America/Araguaina", -7.19, -48.2),
+    timezone("America/Argentina/Buenos_Aires", -34.6, -58.45),
+    timezone("America/Argentina/Catamarca", -28.4667, -65.7833),
+    timezone("America/Argentina/Cordoba", -31.4, -64.1833),
+    timezone("America/Argentina/Jujuy", -24.1833, -65.3),
+    timezone("America/Argentina/La_Rioja", -29.4333, -66.85),
+    timezone("America/Argentina/Mendoza", -32.8833, -68.8167),
+    timezone("America/Argentina/Rio_Gallegos", -51.6333, -69.2167),
+    timezone("America/Argentina/Salta", -24.7833, -65.4167),
+    timezone("America/Argentina/San_Juan", -31.5333, -68.5167),
+    timezone("America/Argentina/San_Luis", -33.3167, -66.35),
+    timezone("America/Argentina/Tucuman", -26.8167, -65.2167),
+    timezone("America/Argentina/Ushuaia", -54.8, -68.3),
+    timezone("America/Aruba", 12.5, -69.9667),
+    timezone("America/Asuncion", -25.2667, -57.6667),
+    timezone("America/Atikokan", 48.7586, -91.6217),
+    timezone("America/Bahia", -12.9833, -38.5167),
+    timezone("America/Bahia_Banderas", 20.8, -105.25),
+    timezone("America/Barbados", 13.1, -59.6167),
+    timezone("America/Belem", -1.45, -48.4833),
+    timezone("America/Belize", 17.5, -88.2),
+    timezone("America/Blanc-Sablon", 51.4167, -57.1167),
+    timezone("America/Boa_Vista", 2.8167, -60.6667),
+    timezone("America/Bogota", 4.6, -74.0833),
+    timezone("America/Boise", 43.6136, -116.2025),
+    timezone("America/Cambridge_Bay", 69.1139, -105.0528),
+    timezone("America/Campo_Grande", -20.45, -54.6167),
+    timezone("America/Cancun", 21.0833, -86.7667),
+    timezone("America/Caracas", 10.5, -66.9333),
+    timezone("America/Cayenne", 4.9333, -52.3333),
+    timezone("America/Cayman", 19.3, -81.3833),
+    timezone("America/Chicago", 41.85, -87.65),
+    timezone("America/Chihuahua", 28.6333, -106.0833),
+    timezone("America/Ciudad_Juarez", 31.7333, -106.4833),
+    timezone("America/Costa_Rica", 9.9333, -84.0833),
+    timezone("America/Cuiaba", -15.5833, -56.0833),
+    timezone("America/Curacao", 12.1833, -69.0),
+    timezone("America/Danmarkshavn", 76.7667, -18.6667),
+    timezone("America/Dawson", 64.0667, -139.4167),
+    timezone("America/Dawson_Creek", 55.7667, -120.2333),
+    timezone("America/Denver", 39.7392, -104.9842),
+    timezone("America/Detroit", 42.3314, -83.0458),
+    timezone("America/Dominica", 15.3, -61.4),
+    timezone("America/Edmonton", 53.55, -113.4667),
+    timezone("America/Eirunepe", -6.6667, -69.8667),
+    timezone("America/El_Salvador", 13.7, -89.2),
+    timezone("America/Fort_Nelson", 58.8, -122.7),
+    timezone("America/Fortaleza", -3.7167, -38.5),
+    timezone("America/Glace_Bay", 46.2, -59.95),
+    timezone("America/Goose_Bay", 53.3333, -60.4167),
+    timezone("America/Grand_Turk", 21.4667, -71.1333),
+    timezone("America/Grenada", 12.05, -61.75),
+    timezone("America/Guadeloupe", 16.2333, -61.5333),
+    timezone("America/Guatemala", 14.6333, -90.5167),
+    timezone("America/Guayaquil", -2.1667, -79.8333),
+    timezone("America/Guyana", 6.8, -58.1667),
+    timezone("America/Halifax", 44.65, -63.6),
+    timezone("America/Havana", 23.1333, -82.3667),
+    timezone("America/Hermosillo", 29.0667, -110.9667),
+    timezone("America/Indiana/Indianapolis", 39.7683, -86.1581),
+    timezone("America/Indiana/Knox", 41.2958, -86.625),
+    timezone("America/Indiana/Marengo", 38.375, -86.3447),
+    timezone("America/Indiana/Petersburg", 38.4919, -87.2786),
+    timezone("America/Indiana/Tell_City", 37.9531, -86.7614),
+    timezone("America/Indiana/Vevay", 38.7478, -85.0672),
+    timezone("America/Indiana/Vincennes", 38.6772, -87.5286),
+    timezone("America/Indiana/Winamac", 41.0514, -86.6031),
+    timezone("America/Inuvik", 68.3497, -133.7167),
+    timezone("America/Iqaluit", 63.7333, -68.4667),
+    timezone("America/Jamaica", 17.9681, -76.7933),
+    timezone("America/Juneau", 58.3019, -134.4197),
+    timezone("America/Kentucky/Louisville", 38.2542, -85.7594),
+    timezone("America/Kentucky/Monticello", 36.8297, -84.8492),
+    timezone("America/Kralendijk", 12.1508, -68.2767),
+    timezone("America/La_Paz", -16.5, -68.15),
+    timezone("America/Lima", -12.05, -77.05),
+    timezone("America/Los_Angeles", 34.0522, -118.2428),
+    timezone("America/Lower_Princes", 18.0514, -63.0472),
+    timezone("America/Maceio", -9.6667, -35.7167),
+    timezone("America/Managua", 12.15, -86.2833),
+    timezone("America/Manaus", -3.1333, -60.0167),
+    timezone("America/Marigot", 18.0667, -63.0833),
+    timezone("America/Martinique", 14.6, -61.0833),
+    timezone("America/Matamoros", 25.8333, -97.5),
+    timezone("America/Mazatlan", 23.2167, -106.4167),
+    timezone("America/Menominee", 45.1078, -87.6142),
+    timezone("America/Merida", 20.9667, -89.6167),
+    timezone("America/Metlakatla", 55.1269, -131.5764),
+    timezone("America/Mexico_City", 19.4, -99.15),
+    timezone("America/Miquelon", 47.05, -56.3333),
+    timezone("America/Moncton", 46.1, -64.7833),
+    timezone("America/Monterrey", 25.6667, -100.3167),
+    timezone("America/Montevideo", -34.9092, -56.2125),
+    timezone("America/Montserrat", 16.7167, -62.2167),
+    timezone("America/Nassau", 25.0833, -77.35),
+    timezone("America/New_York", 40.7142, -74.0064),
+    timezone("America/Nome", 64.5011, -165.4064),
+    timezone("America/Noronha", -3.85, -32.4167),
+    timezone("America/North_Dakota/Beulah", 47.2642, -101.7778),
+    timezone("America/North_Dakota/Center", 47.1164, -101.2992),
+    timezone("America/North_Dakota/New_Salem", 46.845, -101.4108),
+    timezone("America/Nuuk", 64.1833, -51.7333),
+    timezone("America/Ojinaga", 29.5667, -104.4167),
+    timezone("America/Panama", 8.9667, -79.5333),
+    timezone("America/Paramaribo", 5.8333, -55.1667),
+    timezone("America/Phoenix", 33.4483, -112.0733),
+    timezone("America/Port-au-Prince", 18.5333, -72.3333),
+    timezone("America/Port_of_Spain", 10.65, -61.5167),
+    timezone("America/Porto_Velho", -8.7667, -63.9),
+    timezone("America/Puerto_Rico", 18.4683, -66.1061),
+    timezone("America/Punta_Arenas", -53.15, -70.9167),
+    timezone("America/Rankin_Inlet", 62.8167, -92.0831),
+    timezone("America/Recife", -8.05, -34.9),
+    timezone("America/Regina", 50.4, -104.65),
+    timezone("America/Resolute", 74.6956, -94.8292),
+    timezone("America/Rio_Branco", -9.9667, -67.8),
+    timezone("America/Santarem", -2.4333, -54.8667),
+    timezone("America/Santiago", -33.45, -70.6667),
+    timezone("America/Santo_Domingo", 18.4667, -69.9),
+    timezone("America/Sao_Paulo", -23.5333, -46.6167),
+    timezone("America/Scoresbysund", 70.4833, -21.9667),
+    timezone("America/Sitka", 57.1764, -135.3019),
+    timezone("America/St_Barthelemy", 17.8833, -62.85),
+    timezone("America/St_Johns", 47.5667, -52.7167),
+    timezone("America/St_Kitts", 17.3, -62.7167),
+    timezone("America/St_Lucia", 14.0167, -61.0),
+    timezone("America/St_Thomas", 18.35, -64.9333),
+    timezone("America/St_Vincent", 13.15, -61.2333),
+    timezone("America/Swift_Current", 50.2833, -107.8333),
+    timezone("America/Tegucigalpa", 14.1, -87.2167),
+    timezone("America/Thule", 76.5667, -68.7833),
+    timezone("America/Tijuana", 32.5333, -117.0167),
+    timezone("America/Toronto", 43.65, -79.3833),
+    timezone("America/Tortola", 18.45, -64.6167),
+    timezone("America/Vancouver", 49.2667, -123.1167),
+    timezone("America/Whitehorse", 60.7167, -135.05),
+    timezone("America/Winnipeg", 49.8833, -97.15),
+    timezone("America/Yakutat", 59.5469, -139.7272),
+    timezone("Antarctica/Casey", -66.2833, 110.5167),
+    timezone("Antarctica/Davis", -68.5833, 77.9667),
+    timezone("Antarctica/DumontDUrville", -66.6667, 140.0167),
+    timezone("Antarctica/Macquarie", -54.5, 158.95),
+    timezone("Antarctica/Mawson", -67.6, 62.8833),
+    timezone("Antarctica/McMurdo", -77.8333, 166.6),
+    timezone("Antarctica/Palmer", -64.8, -64.1),
+    timezone("Antarctica/Rothera", -67.5667, -68.1333),
+    timezone("Antarctica/Syowa", -69.0061, 39.59),
+    timezone("Antarctica/Troll", -72.0114, 2.535),
+    timezone("Antarctica/Vostok", -78.4, 106.9),
+    timezone("Arctic/Longyearbyen", 78.0, 16.0),
+    timezone("Asia/Aden", 12.75, 45.2),
+    timezone("Asia/Almaty", 43.25, 76.95),
+    timezone("Asia/Amman", 31.95, 35.9333),
+    timezone("Asia/Anadyr", 64.75, 177.4833),
+    timezone("Asia/Aqtau", 44.5167, 50.2667),
+    timezone("Asia/Aqtobe", 50.2833, 57.1667),
+    timezone("Asia/Ashgabat", 37.95, 58.3833),
+    timezone("Asia/Atyrau", 47.1167, 51.9333),
+    timezone("Asia/Baghdad", 33.35, 44.4167),
+    timezone("Asia/Bahrain", 26.3833, 50.5833),
+    timezone("Asia/Baku", 40.3833, 49.85),
+    timezone("Asia/Bangkok", 13.75, 100.5167),
+    timezone("Asia/Barnaul", 53.3667, 83.75),
+    timezone("Asia/Beirut", 33.8833, 35.5),
+    timezone("Asia/Bishkek", 42.9, 74.6),
+    timezone("Asia/Brunei", 4.9333, 114.9167),
+    timezone("Asia/Chita", 52.05, 113.4667),
+    timezone("Asia/Colombo", 6.9333, 79.85),
+    timezone("Asia/Damascus", 33.5, 36.3),
+    timezone("Asia/Dhaka", 23.7167, 90.4167),
+    timezone("Asia/Dili", -8.55, 125.5833),
+    timezone("Asia/Dubai", 25.3, 55.3),
+    timezone("Asia/Dushanbe", 38.5833, 68.8),
+    timezone("Asia/Famagusta", 35.1167, 33.95),
+    timezone("Asia/Gaza", 31.5, 34.4667),
+    timezone("Asia/Hebron", 31.5333, 35.095),
+    timezone("Asia/Ho_Chi_Minh", 10.75, 106.6667),
+    timezone("Asia/Hong_Kong", 22.2833, 114.15),
+    timezone("Asia/Hovd", 48.0167, 91.65),
+    timezone("Asia/Irkutsk", 52.2667, 104.3333),
+    timezone("Asia/Jakarta", -6.1667, 106.8),
+    timezone("Asia/Jayapura", -2.5333, 140.7),
+    timezone("Asia/Jerusalem", 31.7806, 35.2239),
+    timezone("Asia/Kabul", 34.5167, 69.2),
+    timezone("Asia/Kamchatka", 53.0167, 158.65),
+    timezone("Asia/Karachi", 24.8667, 67.05),
+    timezone("Asia/Kathmandu", 27.7167, 85.3167),
+    timezone("Asia/Khandyga", 62.6564, 135.5539),
+    timezone("Asia/Kolkata", 22.5333, 88.3667),
+    timezone("Asia/Krasnoyarsk", 56.0167, 92.8333),
+    timezone("Asia/Kuala_Lumpur", 3.1667, 101.7),
+    timezone("Asia/Kuching", 1.55, 110.3333),
+    timezone("Asia/Kuwait", 29.3333, 47.9833),
+    timezone("Asia/Macau", 22.1972, 113.5417),
+    timezone("Asia/Magadan", 59.5667, 150.8),
+    timezone("Asia/Makassar", -5.1167, 119.4),
+    timezone("Asia/Manila", 14.5864, 120.9678),
+    timezone("Asia/Muscat", 23.6, 58.5833),
+    timezone("Asia/Nicosia", 35.1667, 33.3667),
+    timezone("Asia/Novokuznetsk", 53.75, 87.1167),
+    timezone("Asia/Novosibirsk", 55.0333, 82.9167),
+    timezone("Asia/Omsk", 55.0, 73.4),
+    timezone("Asia/Oral", 51.2167, 51.35),
+    timezone("Asia/Phnom_Penh", 11.55, 104.9167),
+    timezone("Asia/Pontianak", -0.0333, 109.3333),
+    timezone("Asia/Pyongyang", 39.0167, 125.75),
+    timezone("Asia/Qatar", 25.2833, 51.5333),
+    timezone("Asia/Qostanay", 53.2, 63.6167),
+    timezone("Asia/Qyzylorda", 44.8, 65.4667),
+    timezone("Asia/Riyadh", 24.6333, 46.7167),
+    timezone("Asia/Sakhalin", 46.9667, 142.7),
+    timezone("Asia/Samarkand", 39.6667, 66.8),
+    timezone("Asia/Seoul", 37.55, 126.9667),
+    timezone("Asia/Shanghai", 31.2333, 121.4667),
+    timezone("Asia/Singapore", 1.2833, 103.85),
+    timezone("Asia/Srednekolymsk", 67.4667, 153.7167),
+    timezone("Asia/Taipei", 25.05, 121.5),
+    timezone("Asia/Tashkent", 41.3333, 69.3),
+    timezone("Asia/Tbilisi", 41.7167, 44.8167),
+    timezone("Asia/Tehran", 35.6667, 51.4333),
+    timezone("Asia/Thimphu", 27.4667, 89.65),
+    timezone("Asia/Tokyo", 35.6544, 139.7447),
+    timezone("Asia/Tomsk", 56.5, 84.9667),
+    timezone("Asia/Ulaanbaatar", 47.9167, 106.8833),
+    timezone("Asia/Urumqi", 43.8, 87.5833),
+    timezone("Asia/Ust-Nera", 64.5603, 143.2267),
+    timezone("Asia/Vientiane", 17.9667, 102.6),
+    timezone("Asia/Vladivostok", 43.1667, 131.9333),
+    timezone("Asia/Yakutsk", 62.0, 129.6667),
+    timezone("Asia/Yangon", 16.7833, 96.1667),
+    timezone("Asia/Yekaterinburg", 56.85, 60.6),
+    timezone("Asia/Yerevan", 40.1833, 44.5),
+    timezone("Atlantic/Azores", 37.7333, -25.6667),
+    timezone("Atlantic/Bermuda", 32.2833, -64.7667),
+    timezone("Atlantic/Canary", 28.1, -15.4),
+    timezone("Atlantic/Cape_Verde", 14.9167, -23.5167),
+    timezone("Atlantic/Faroe", 62.0167, -6.7667),
+    timezone("Atlantic/Madeira", 32.6333, -16.9),
+    timezone("Atlantic/Reykjavik", 64.15, -21.85),
+    timezone("Atlantic/South_Georgia", -54.2667, -36.5333),
+    timezone("Atlantic/St_Helena", -15.9167, -5.7),
+    timezone("Atlantic/Stanley", -51.7, -57.85),
+    timezone("Australia/Adelaide", -34.9167, 138.5833),
+    timezone("Australia/Brisbane", -27.4667, 153.0333),
+    timezone("Australia/Broken_Hill", -31.95, 141.45),
+    timezone("Australia/Darwin", -12.4667, 130.8333),
+    timezone("Australia/Eucla", -31.7167, 128.8667),
+    timezone("Australia/Hobart", -42.8833, 147.3167),
+    timezone("Australia/Lindeman", -20.2667, 149.0),
+    timezone("Australia/Lord_Howe", -31.55, 159.0833),
+    timezone("Australia/Melbourne", -37.8167, 144.9667),
+    timezone("Australia/Perth", -31.95, 115.85),
+    timezone("Australia/Sydney", -33.8667, 151.2167),
+    timezone("Europe/Amsterdam", 52.3667, 4.9),
+    timezone("Europe/Andorra", 42.5, 1.5167),
+    timezone("Europe/Astrakhan", 46.35, 48.05),
+    timezone("Europe/Athens", 37.9667, 23.7167),
+    timezone("Europe/Belgrade", 44.8333, 20.5),
+    timezone("Europe/Berlin", 52.5, 13.3667),
+    timezone("Europe/Bratislava", 48.15, 17.1167),
+    timezone("Europe/Brussels", 50.8333, 4.3333),
+    timezone("Europe/Bucharest", 44.4333, 26.1),
+    timezone("Europe/Budapest", 47.5, 19.0833),
+    timezone("Europe/Busingen", 47.7, 8.6833),
+    timezone("Europe/Chisinau", 47.0, 28.8333),
+    timezone("Europe/Copenhagen", 55.6667, 12.5833),
+    timezone("Europe/Dublin", 53.3333, -6.25),
+    timezone("Europe/Gibraltar", 36.1333, -5.35),
+    timezone("Europe/Guernsey", 49.4558, -2.5361),
+    timezone("Europe/Helsinki", 60.1667, 24.9667),
+    timezone("Europe/Isle_of_Man", 54.15, -4.4667),
+    timezone("Europe/Istanbul", 41.0167, 28.9667),
+    timezone("Europe/Jersey", 49.1836, -2.1067),
+    timezone("Europe/Kaliningrad", 54.7167, 20.5),
+    timezone("Europe/Kirov", 58.6, 49.65),
+    timezone("Europe/Kyiv", 50.4333, 30.5167),
+    timezone("Europe/Lisbon", 38.7167, -9.1333),
+    timezone("Europe/Ljubljana", 46.05, 14.5167),
+    timezone("Europe/London", 51.5083, -0.1253),
+    timezone("Europe/Luxembourg", 49.6, 6.15),
+    timezone("Europe/Madrid", 40.4, -3.6833),
+    timezone("Europe/Malta", 35.9, 14.5167),
+    timezone("Europe/Mariehamn", 60.1, 19.95),
+    timezone("Europe/Minsk", 53.9, 27.5667),
+    timezone("Europe/Monaco", 43.7, 7.3833),
+    timezone("Europe/Moscow", 55.7558, 37.6178),
+    timezone("Europe/Oslo", 59.9167, 10.75),
+    timezone("Europe/Paris", 48.8667, 2.3333),
+    timezone("Europe/Podgorica", 42.4333, 19.2667),
+    timezone("Europe/Prague", 50.0833, 14.4333),
+    timezone("Europe/Riga", 56.95, 24.1),
+    timezone("Europe/Rome", 41.9, 12.4833),
+    timezone("Europe/Samara", 53.2, 50.15),
+    timezone("Europe/San_Marino", 43.9167, 12.4667),
+    timezone("Europe/Sarajevo", 43.8667, 18.4167),
+    timezone("Europe/Saratov", 51.5667, 46.0333),
+    timezone("Europe/Simferopol", 44.95, 34.1),
+    timezone("Europe/Skopje", 41.9833, 21.4333),
+    timezone("Europe/Sofia", 42.6833, 23.3167),
+    timezone("Europe/Stockholm", 59.3333, 18.05),
+    timezone("Europe/Tallinn", 59.4167, 24.75),
+    timezone("Europe/Tirane", 41.3333, 19.8333),
+    timezone("Europe/Ulyanovsk", 54.3333, 48.4),
+    timezone("Europe/Vaduz", 47.15, 9.5167),
+    timezone("Europe/Vatican", 41.9022, 12.4531),
+    timezone("Europe/Vienna", 48.2167, 16.3333),
+    timezone("Europe/Vilnius", 54.6833, 25.3167),
+    timezone("Europe/Volgograd", 48.7333, 44.4167),
+    timezone("Europe/Warsaw", 52.25, 21.0),
+    timezone("Europe/Zagreb", 45.8, 15.9667),
+    timezone("Europe/Zurich", 47.3833, 8.5333),
+    timezone("Indian/Antananarivo", -18.9167, 47.5167),
+    timezone("Indian/Chagos", -7.3333, 72.4167),
+    timezone("Indian/Christmas", -10.4167, 105.7167),
+    timezone("Indian/Cocos", -12.1667, 96.9167),
+    timezone("Indian/Comoro", -11.6833, 43.2667),
+    timezone("Indian/Kerguelen", -49.3527, 70.2175),
+    timezone("Indian/Mahe", -4.6667, 55.4667),
+    timezone("Indian/Maldives", 4.1667, 73.5),
+    timezone("Indian/Mauritius", -20.1667, 57.5),
+    timezone("Indian/Mayotte", -12.7833, 45.2333),
+    timezone("Indian/Reunion", -20.8667, 55.4667),
+    timezone("Pacific/Apia", -13.8333, -171.7333),
+    timezone("Pacific/Auckland", -36.8667, 174.7667),
+    timezone("Pacific/Bougainville", -6.2167, 155.5667),
+    timezone("Pacific/Chatham", -43.95, -176.55),
+    timezone("Pacific/Chuuk", 7.4167, 151.7833),
+    timezone("Pacific/Easter", -27.15, -109.4333),
+    timezone("Pacific/Efate", -17.6667, 168.4167),
+    timezone("Pacific/Fakaofo", -9.3667, -171.2333),
+    timezone("Pacific/Fiji", -18.1333, 178.4167),
+    timezone("Pacific/Funafuti", -8.5167, 179.2167),
+    timezone("Pacific/Galapagos", -0.9, -89.6),
+    timezone("Pacific/Gambier", -23.1333, -134.95),
+    timezone("Pacific/Guadalcanal", -9.5333, 160.2),
+    timezone("Pacific/Guam", 13.4667, 144.75),
+    timezone("Pacific/Honolulu", 21.3069, -157.8583),
+    timezone("Pacific/Kanton", -2.7833, -171.7167),
+    timezone("Pacific/Kiritimati", 1.8667, -157.3333),
+    timezone("Pacific/Kosrae", 5.3167, 162.9833),
+    timezone("Pacific/Kwajalein", 9.0833, 167.3333),
+    timezone("Pacific/Majuro", 7.15, 171.2),
+    timezone("Pacific/Marquesas", -9.0, -139.5),
+    timezone("Pacific/Midway", 28.2167, -177.3667),
+    timezone("Pacific/Nauru", -0.5167, 166.9167),
+    timezone("Pacific/Niue", -19.0167, -169.9167),
+    timezone("Pacific/Norfolk", -29.05, 167.9667),
+    timezone("Pacific/Noumea", -22.2667, 166.45),
+    timezone("Pacific/Pago_Pago", -14.2667, -170.7),
+    timezone("Pacific/Palau", 7.3333, 134.4833),
+    timezone("Pacific/Pitcairn", -25.0667, -130.0833),
+    timezone("Pacific/Pohnpei", 6.9667, 158.2167),
+    timezone("Pacific/Port_Moresby", -9.5, 147.1667),
+    timezone("Pacific/Rarotonga", -21.2333, -159.7667),
+    timezone("Pacific/Saipan", 15.2, 145.75),
+    timezone("Pacific/Tahiti", -17.5333, -149.5667),
+    timezone("Pacific/Tarawa", 1.4167, 173.0),
+    timezone("Pacific/Tongatapu", -21.1333, -175.2),
+    timezone("Pacific/Wake", 19.2833, 166.6167),
+    timezone("Pacific/Wallis", -13.3, -176.1667),
+];
+
+/// Deprecated time zone names and the canonical zone they link to. Based on
+/// `backward` of the tz database.
+const TIMEZONE_ALIASES: [(&str, &str); 98] = [
+    ("Africa/Asmera", "Africa/Asmara"),
+    ("Africa/Timbuktu", "Africa/Bamako"),
+    ("America/Atka", "America/Adak"),
+    ("America/Buenos_Aires", "America/Argentina/Buenos_Aires"),
+    ("America/Catamarca", "America/Argentina/Catamarca"),
+    ("America/Coral_Harbour", "America/Atikokan"),
+    ("America/Cordoba", "America/Argentina/Cordoba"),
+    ("America/Ensenada", "America/Tijuana"),
+    ("America/Fort_Wayne", "America/Indiana/Indianapolis"),
+    ("America/Godthab", "America/Nuuk"),
+    ("America/Indianapolis", "America/Indiana/Indianapolis"),
+    ("America/Jujuy", "America/Argentina/Jujuy"),
+    ("America/Knox_IN", "America/Indiana/Knox"),
+    ("America/Louisville", "America/Kentucky/Louisville"),
+    ("America/Mendoza", "America/Argentina/Mendoza"),
+    ("America/Montreal", "America/Toronto"),
+    ("America/Porto_Acre", "America/Rio_Branco"),
+    ("America/Santa_Isabel", "America/Tijuana"),
+    ("America/Shiprock", "America/Denver"),
+    ("America/Virgin", "America/St_Thomas"),
+    ("Asia/Ashkhabad", "Asia/Ashgabat"),
+    ("Asia/Calcutta", "Asia/Kolkata"),
+    ("Asia/Choibalsan", "Asia/Ulaanbaatar"),
+    ("Asia/Chongqing", "Asia/Shanghai"),
+    ("Asia/Chungking", "Asia/Shanghai"),
+    ("Asia/Dacca", "Asia/Dhaka"),
+    ("Asia/Harbin", "Asia/Shanghai"),
+    ("Asia/Istanbul", "Europe/Istanbul"),
+    ("Asia/Kashgar", "Asia/Urumqi"),
+    ("Asia/Katmandu", "Asia/Kathmandu"),
+    ("Asia/Macao", "Asia/Macau"),
+    ("Asia/Rangoon", "Asia/Yangon"),
+    ("Asia/Saigon", "Asia/Ho_Chi_Minh"),
+    ("Asia/Tel_Aviv", "Asia/Jerusalem"),
+    ("Asia/Thimbu", "Asia/Thimphu"),
+    ("Asia/Ujung_Pandang", "Asia/Makassar"),
+    ("Asia/Ulan_Bator", "Asia/Ulaanbaatar"),
+    ("Atlantic/Faeroe", "Atlantic/Faroe"),
+    ("Atlantic/Jan_Mayen", "Arctic/Longyearbyen"),
+    ("Australia/ACT", "Australia/Sydney"),
+    ("Australia/Canberra", "Australia/Sydney"),
+    ("Australia/NSW", "Australia/Sydney"),
+    ("Australia/North", "Australia/Darwin"),
+    ("Australia/Queensland", "Australia/Brisbane"),
+    ("Australia/South", "Australia/Adelaide"),
+    ("Australia/Tasmania", "Australia/Hobart"),
+    ("Australia/Victoria", "Australia/Melbourne"),
+    ("Australia/West", "Australia/Perth"),
+    ("Brazil/East", "America/Sao_Paulo"),
+    ("Canada/Atlantic", "America/Halifax"),
+    ("Canada/Central", "America/Winnipeg"),
+    ("Canada/Eastern", "America/Toronto"),
+    ("Canada/Mountain", "America/Edmonton"),
+    ("Canada/Newfoundland", "America/St_Johns"),
+    ("Canada/Pacific", "America/Vancouver"),
+    ("Chile/Continental", "America/Santiago"),
+    ("Cuba", "America/Havana"),
+    ("Egypt", "Africa/Cairo"),
+    ("Eire", "Europe/Dublin"),
+    ("Europe/Belfast", "Europe/London"),
+    ("Europe/Kiev", "Europe/Kyiv"),
+    ("Europe/Nicosia", "Asia/Nicosia"),
+    ("Europe/Tiraspol", "Europe/Chisinau"),
+    ("Europe/Uzhgorod", "Europe/Kyiv"),
+    ("Europe/Zaporozhye", "Europe/Kyiv"),
+    ("GB", "Europe/London"),
+    ("Hongkong", "Asia/Hong_Kong"),
+    ("Iceland", "Atlantic/Reykjavik"),
+    ("Iran", "Asia/Tehran"),
+    ("Israel", "Asia/Jerusalem"),
+    ("Jamaica", "America/Jamaica"),
+    ("Japan", "Asia/Tokyo"),
+    ("Mexico/General", "America/Mexico_City"),
+    ("NZ", "Pacific/Auckland"),
+    ("PRC", "Asia/Shanghai"),
+    ("Pacific/Enderbury", "Pacific/Kanton"),
+    ("Pacific/Ponape", "Pacific/Pohnpei"),
+    ("Pacific/Samoa", "Pacific/Pago_Pago"),
+    ("Pacific/Truk", "Pacific/Chuuk"),
+    ("Pacific/Yap", "Pacific/Chuuk"),
+    ("Poland", "Europe/Warsaw"),
+    ("Portugal", "Europe/Lisbon"),
+    ("ROC", "Asia/Taipei"),
+    ("ROK", "Asia/Seoul"),
+    ("Singapore", "Asia/Singapore"),
+    ("Turkey", "Europe/Istanbul"),
+    ("US/Alaska", "America/Anchorage"),
+    ("US/Aleutian", "America/Adak"),
+    ("US/Arizona", "America/Phoenix"),
+    ("US/Central", "America/Chicago"),
+    ("US/East-Indiana", "America/Indiana/Indianapolis"),
+    ("US/Eastern", "America/New_York"),
+    ("US/Hawaii", "Pacific/Honolulu"),
+    ("US/Michigan", "America/Detroit"),
+    ("US/Mountain", "America/Denver"),
+    ("US/Pacific", "America/Los_Angeles"),
+    ("US/Samoa", "Pacific/Pago_Pago"),
+    ("W-SU", "Europe/Moscow"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_timezone_from_str() {
+        struct Test {
+            name: &'static str,
+            timezone: Result<&'static str, Error>,
+        }
+
+        let tests = vec![
+            Test {
+                name: "Europe/Berlin",
+                timezone: Ok("Europe/Berlin"),
+            },
+            Test {
+                name: "america/new_york",
+                timezone: Ok("America/New_York"),
+            },
+            Test {
+                name: "Asia/Calcutta",
+                timezone: Ok("Asia/Kolkata"),
+            },
+            Test {
+                name: "US/Pacific",
+                timezone: Ok("America/Los_Angeles"),
+            },
+            Test {
+                name: "UTC",
+                timezone: Err(Error::InvalidTimezone),
+            },
+            Test {
+                name: "Etc/GMT+2",
+                timezone: Err(Error::InvalidTimezone),
+            },
+            Test {
+                name: "Europe/Atlantis",
+                timezone: Err(Error::InvalidTimezone),
+            },
+        ];
+
+        for t in tests {
+            let timezone = t.name.parse::<Timezone>().map(|timezone| timezone.name());
+            assert_eq!(timezone, t.timezone, "{}", t.name);
+        }
+
+        assert_eq!(Timezone::try_from("Europe/Kiev").unwrap().to_string(), "Europe/Kyiv");
+    }
+
+    #[test]
+    fn test_timezone_iter() {
+        // Sorted and unique
+        let names: Vec<&str> = Timezone::iter().map(|timezone| timezone.name()).collect();
+        assert!(names.windows(2).all(|w| w[0] < w[1]));
+
+        assert!(TIMEZONE_ALIASES.iter().all(|(_, name)| names.contains(name)));
+    }
+
+    #[test]
+    fn test_find_region_for_timezone() {
+        struct Test {
+            name: &'static str,
+            list: Vec<AwsRegion>,
+            region: AwsRegion,
+        }
+
+        let tests = vec![
+            Test {
+                name: "Europe/Berlin",
+                list: vec![AwsRegion::EuWest1, AwsRegion::EuCentral1, AwsRegion::UsEast1],
+                region: AwsRegion::EuCentral1,
+            },
+            Test {
+                name: "America/Los_Angeles",
+                list: vec![AwsRegion::UsEast1, AwsRegion::UsWest1, AwsRegion::UsWest2],
+                region: AwsRegion::UsWest1,
+            },
+            Test {
+                name: "Asia/Kolkata",
+                list: vec![AwsRegion::ApSouth1, AwsRegion::ApSoutheast1],
+                region: AwsRegion::ApSouth1,
+            },
+            Test {
+                name: "Australia/Perth",
+                list: vec![AwsRegion::ApSoutheast2, AwsRegion::ApSoutheast3],
+                region: AwsRegion::ApSoutheast3,
+            },
+        ];
+
+        for t in tests {
+            let region = find_region_for_timezone(t.name, &t.list);
+            assert_eq!(region, Ok(t.region), "{}", t.name);
+        }
+
+        assert_eq!(
+            find_region_for_timezone("UTC", &[AwsRegion::EuWest1]),
+            Err(Error::InvalidTimezone)
+        );
+    }
+}
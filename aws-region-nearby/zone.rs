@@ -0,0 +1,928 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::aws::AwsRegion;
+use crate::latlon::{IntoLatLon, LatLon};
+use crate::Error;
+
+/// The type of an [`AwsZone`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum ZoneType {
+    /// A Local Zone, placing compute and storage in a metro area away from
+    /// its parent region.
+    LocalZone,
+
+    /// A Wavelength Zone, placing compute and storage in the 5G network of a
+    /// telecommunications provider.
+    WavelengthZone,
+}
+
+impl ZoneType {
+    /// Returns the name of the zone type as used by the EC2 API, e.g.
+    /// `local-zone`.
+    pub const fn name(&self) -> &'static str {
+        match *self {
+            Self::LocalZone => "local-zone",
+            Self::WavelengthZone => "wavelength-zone",
+        }
+    }
+}
+
+impl fmt::Display for ZoneType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// An AWS Local Zone or Wavelength Zone, an extension of a parent region.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AwsZone {
+    name: &'static str,
+    id: &'static str,
+    region: AwsRegion,
+    zone_type: ZoneType,
+    city: &'static str,
+    latitude: f64,
+    longitude: f64,
+}
+
+impl AwsZone {
+    /// Returns an iterator over all known zones.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        ZONES.iter().copied()
+    }
+
+    /// Returns the name of the zone, e.g. `us-west-2-lax-1a`.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the ID of the zone, e.g. `usw2-lax1-az1`. Unlike names, IDs
+    /// are the same in all AWS accounts.
+    pub const fn id(&self) -> &'static str {
+        self.id
+    }
+
+    /// Returns the network border group of the zone, e.g. `us-west-2-lax-1`,
+    /// which is the group a zone is opted into.
+    pub fn network_border_group(&self) -> &'static str {
+        match self.zone_type {
+            ZoneType::LocalZone => self.name.trim_end_matches(|c: char| c.is_ascii_lowercase()),
+            ZoneType::WavelengthZone => self.name,
+        }
+    }
+
+    /// Returns the parent region of the zone.
+    pub const fn region(&self) -> AwsRegion {
+        self.region
+    }
+
+    /// Returns the type of the zone.
+    pub const fn zone_type(&self) -> ZoneType {
+        self.zone_type
+    }
+
+    /// Returns the name of the metro area the zone is located in.
+    pub const fn city(&self) -> &'static str {
+        self.city
+    }
+
+    /// Returns the location of the zone.
+    pub const fn location(&self) -> LatLon {
+        LatLon::new(self.latitude, self.longitude)
+    }
+
+    /// Returns the distance in meters between the zone and the given location.
+    pub fn distance_to<L: IntoLatLon>(&self, to: L) -> f64 {
+        self.location().distance_to(to)
+    }
+}
+
+impl IntoLatLon for AwsZone {
+    fn into_lat_lon(self) -> LatLon {
+        self.location()
+    }
+}
+
+impl fmt::Display for AwsZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+impl FromStr for AwsZone {
+    type Err = Error;
+
+    /// Parses the zone from its name, ID or network border group, ignoring
+    /// ASCII case.
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Self::iter()
+            .find(|zone| {
+                zone.name.eq_ignore_ascii_case(s)
+                    || zone.id.eq_ignore_ascii_case(s)
+                    || zone.network_border_group().eq_ignore_ascii_case(s)
+            })
+            .ok_or(Error::InvalidAwsZone)
+    }
+}
+
+impl TryFrom<&str> for AwsZone {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Error> {
+        s.parse()
+    }
+}
+
+/// A region or zone to route to.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum RoutingTarget {
+    /// An AWS region.
+    Region(AwsRegion),
+
+    /// A Local Zone or Wavelength Zone.
+    Zone(AwsZone),
+}
+
+impl RoutingTarget {
+    /// Returns the name of the region or zone.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Region(region) => region.name(),
+            Self::Zone(zone) => zone.name(),
+        }
+    }
+
+    /// Returns the region, or the parent region of the zone.
+    pub const fn region(&self) -> AwsRegion {
+        match self {
+            Self::Region(region) => *region,
+            Self::Zone(zone) => zone.region(),
+        }
+    }
+
+    /// Returns the location of the region or zone.
+    pub const fn location(&self) -> LatLon {
+        match self {
+            Self::Region(region) => region.location(),
+            Self::Zone(zone) => zone.location(),
+        }
+    }
+
+    /// Returns the distance in meters between the target and the given
+    /// location.
+    pub fn distance_to<L: IntoLatLon>(&self, to: L) -> f64 {
+        self.location().distance_to(to)
+    }
+}
+
+impl From<AwsRegion> for RoutingTarget {
+    fn from(region: AwsRegion) -> Self {
+        Self::Region(region)
+    }
+}
+
+impl From<AwsZone> for RoutingTarget {
+    fn from(zone: AwsZone) -> Self {
+        Self::Zone(zone)
+    }
+}
+
+impl fmt::Display for RoutingTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for RoutingTarget {
+    type Err = Error;
+
+    /// Parses a region name, or else a zone name, ID or network border group.
+    fn from_str(s: &str) -> Result<Self, Error> {
+        if let Some(region) = AwsRegion::from_name(s) {
+            return Ok(Self::Region(region));
+        }
+
+        s.parse().map(Self::Zone)
+    }
+}
+
+impl TryFrom<&str> for RoutingTarget {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Error> {
+        s.parse()
+    }
+}
+
+/// Finds the nearest Local Zone or Wavelength Zone to the given location.
+pub fn find_zone<L: IntoLatLon>(location: L) -> AwsZone {
+    find_zone_from_list(location, &ZONES)
+}
+
+/// Finds the nearest zone from a list of zones.
+///
+/// # Panics
+///
+/// Panics if zones is empty.
+pub fn find_zone_from_list<L: IntoLatLon>(location: L, zones: &[AwsZone]) -> AwsZone {
+    let location = location.into_lat_lon();
+
+    *zones
+        .iter()
+        .min_by_key(|zone| ordered_float::OrderedFloat(zone.distance_to(location)))
+        .expect("zones must not be empty")
+}
+
+/// Finds the nearest target from a list of regions and zones. Of a region and
+/// zone at the same distance, the one listed first is chosen.
+///
+/// # Panics
+///
+/// Panics if targets is empty.
+pub fn find_target_from_list<L: IntoLatLon>(location: L, targets: &[RoutingTarget]) -> RoutingTarget {
+    let location = location.into_lat_lon();
+
+    *targets
+        .iter()
+        .min_by_key(|target| ordered_float::OrderedFloat(target.distance_to(location)))
+        .expect("targets must not be empty")
+}
+
+const fn local_zone(
+    name: &'static str,
+    id: &'static str,
+    region: AwsRegion,
+    city: &'static str,
+    latitude: f64,
+    longitude: f64,
+) -> AwsZone {
+    AwsZone {
+        name,
+        id,
+        region,
+        zone_type: ZoneType::LocalZone,
+        city,
+        latitude,
+        longitude,
+    }
+}
+
+const fn wavelength_zone(
+    name: &'static str,
+    id: &'static str,
+    region: AwsRegion,
+    city: &'static str,
+    latitude: f64,
+    longitude: f64,
+) -> AwsZone {
+    AwsZone {
+        name,
+        id,
+        region,
+        zone_type: ZoneType::WavelengthZone,
+        city,
+        latitude,
+        longitude,
+    }
+}
+
+/// Local Zones and Wavelength Zones by parent region, located at the center
+/// of their metro area.
+static ZONES: [AwsZone; 63] = [
+    local_zone(
+        "af-south-1-los-1a",
+        "afs1-los1-az1",
+        AwsRegion::AfSouth1,
+        "Lagos",
+        6.5244,
+        3.3792,
+    ),
+    local_zone(
+        "ap-northeast-1-tpe-1a",
+        "apne1-tpe1-az1",
+        AwsRegion::ApNortheast1,
+        "Taipei",
+        25.0330,
+        121.5654,
+    ),
+    wavelength_zone(
+        "ap-northeast-1-wl1-kix-wlz-1",
+        "apne1-wl1-kix-wlz1",
+        AwsRegion::ApNortheast1,
+        "Osaka",
+        34.6937,
+        135.5023,
+    ),
+    wavelength_zone(
+        "ap-northeast-1-wl1-nrt-wlz-1",
+        "apne1-wl1-nrt-wlz1",
+        AwsRegion::ApNortheast1,
+        "Tokyo",
+        35.6762,
+        139.6503,
+    ),
+    wavelength_zone(
+        "ap-northeast-2-wl1-cjj-wlz-1",
+        "apne2-wl1-cjj-wlz1",
+        AwsRegion::ApNortheast2,
+        "Daejeon",
+        36.3504,
+        127.3845,
+    ),
+    wavelength_zone(
+        "ap-northeast-2-wl1-sel-wlz-1",
+        "apne2-wl1-sel-wlz1",
+        AwsRegion::ApNortheast2,
+        "Seoul",
+        37.5665,
+        126.9780,
+    ),
+    local_zone(
+        "ap-south-1-ccu-1a",
+        "aps1-ccu1-az1",
+        AwsRegion::ApSouth1,
+        "Kolkata",
+        22.5726,
+        88.3639,
+    ),
+    local_zone(
+        "ap-south-1-del-1a",
+        "aps1-del1-az1",
+        AwsRegion::ApSouth1,
+        "Delhi",
+        28.7041,
+        77.1025,
+    ),
+    local_zone(
+        "ap-southeast-1-bkk-1a",
+        "apse1-bkk1-az1",
+        AwsRegion::ApSoutheast1,
+        "Bangkok",
+        13.7563,
+        100.5018,
+    ),
+    local_zone(
+        "ap-southeast-1-mnl-1a",
+        "apse1-mnl1-az1",
+        AwsRegion::ApSoutheast1,
+        "Manila",
+        14.5995,
+        120.9842,
+    ),
+    local_zone(
+        "ap-southeast-2-akl-1a",
+        "apse2-akl1-az1",
+        AwsRegion::ApSoutheast2,
+        "Auckland",
+        -36.8485,
+        174.7633,
+    ),
+    local_zone(
+        "ap-southeast-2-per-1a",
+        "apse2-per1-az1",
+        AwsRegion::ApSoutheast2,
+        "Perth",
+        -31.9505,
+        115.8605,
+    ),
+    wavelength_zone(
+        "ca-central-1-wl1-yto-wlz-1",
+        "cac1-wl1-yto-wlz1",
+        AwsRegion::CaCentral1,
+        "Toronto",
+        43.6532,
+        -79.3832,
+    ),
+    local_zone(
+        "eu-central-1-ham-1a",
+        "euc1-ham1-az1",
+        AwsRegion::EuCentral1,
+        "Hamburg",
+        53.5511,
+        9.9937,
+    ),
+    local_zone(
+        "eu-central-1-waw-1a",
+        "euc1-waw1-az1",
+        AwsRegion::EuCentral1,
+        "Warsaw",
+        52.2297,
+        21.0122,
+    ),
+    wavelength_zone(
+        "eu-central-1-wl1-ber-wlz-1",
+        "euc1-wl1-ber-wlz1",
+        AwsRegion::EuCentral1,
+        "Berlin",
+        52.5200,
+        13.4050,
+    ),
+    wavelength_zone(
+        "eu-central-1-wl1-dtm-wlz-1",
+        "euc1-wl1-dtm-wlz1",
+        AwsRegion::EuCentral1,
+        "Dortmund",
+        51.5136,
+        7.4653,
+    ),
+    wavelength_zone(
+        "eu-central-1-wl1-muc-wlz-1",
+        "euc1-wl1-muc-wlz1",
+        AwsRegion::EuCentral1,
+        "Munich",
+        48.1351,
+        11.5820,
+    ),
+    local_zone(
+        "eu-north-1-cph-1a",
+        "eun1-cph1-az1",
+        AwsRegion::EuNorth1,
+        "Copenhagen",
+        55.6761,
+        12.5683,
+    ),
+    local_zone(
+        "eu-north-1-hel-1a",
+        "eun1-hel1-az1",
+        AwsRegion::EuNorth1,
+        "Helsinki",
+        60.1699,
+        24.9384,
+    ),
+    wavelength_zone(
+        "eu-west-2-wl1-lon-wlz-1",
+        "euw2-wl1-lon-wlz1",
+        AwsRegion::EuWest2,
+        "London",
+        51.5074,
+        -0.1278,
+    ),
+    wavelength_zone(
+        "eu-west-2-wl1-man-wlz-1",
+        "euw2-wl1-man-wlz1",
+        AwsRegion::EuWest2,
+        "Manchester",
+        53.4808,
+        -2.2426,
+    ),
+    local_zone(
+        "me-south-1-mct-1a",
+        "mes1-mct1-az1",
+        AwsRegion::MeSouth1,
+        "Muscat",
+        23.5880,
+        58.3829,
+    ),
+    local_zone(
+        "us-east-1-atl-1a",
+        "use1-atl1-az1",
+        AwsRegion::UsEast1,
+        "Atlanta",
+        33.7490,
+        -84.3880,
+    ),
+    local_zone(
+        "us-east-1-bos-1a",
+        "use1-bos1-az1",
+        AwsRegion::UsEast1,
+        "Boston",
+        42.3601,
+        -71.0589,
+    ),
+    local_zone(
+        "us-east-1-bue-1a",
+        "use1-bue1-az1",
+        AwsRegion::UsEast1,
+        "Buenos Aires",
+        -34.6037,
+        -58.3816,
+    ),
+    local_zone(
+        "us-east-1-chi-1a",
+        "use1-chi1-az1",
+        AwsRegion::UsEast1,
+        "Chicago",
+        41.8781,
+        -87.6298,
+    ),
+    local_zone(
+        "us-east-1-dfw-1a",
+        "use1-dfw1-az1",
+        AwsRegion::UsEast1,
+        "Dallas",
+        32.7767,
+        -96.7970,
+    ),
+    local_zone(
+        "us-east-1-iah-1a",
+        "use1-iah1-az1",
+        AwsRegion::UsEast1,
+        "Houston",
+        29.7604,
+        -95.3698,
+    ),
+    local_zone(
+        "us-east-1-lim-1a",
+        "use1-lim1-az1",
+        AwsRegion::UsEast1,
+        "Lima",
+        -12.0464,
+        -77.0428,
+    ),
+    local_zone(
+        "us-east-1-mci-1a",
+        "use1-mci1-az1",
+        AwsRegion::UsEast1,
+        "Kansas City",
+        39.0997,
+        -94.5786,
+    ),
+    local_zone(
+        "us-east-1-mia-1a",
+        "use1-mia1-az1",
+        AwsRegion::UsEast1,
+        "Miami",
+        25.7617,
+        -80.1918,
+    ),
+    local_zone(
+        "us-east-1-msp-1a",
+        "use1-msp1-az1",
+        AwsRegion::UsEast1,
+        "Minneapolis",
+        44.9778,
+        -93.2650,
+    ),
+    local_zone(
+        "us-east-1-nyc-1a",
+        "use1-nyc1-az1",
+        AwsRegion::UsEast1,
+        "New York",
+        40.7128,
+        -74.0060,
+    ),
+    local_zone(
+        "us-east-1-phl-1a",
+        "use1-phl1-az1",
+        AwsRegion::UsEast1,
+        "Philadelphia",
+        39.9526,
+        -75.1652,
+    ),
+    local_zone(
+        "us-east-1-qro-1a",
+        "use1-qro1-az1",
+        AwsRegion::UsEast1,
+        "Querétaro",
+        20.5888,
+        -100.3899,
+    ),
+    local_zone(
+        "us-east-1-scl-1a",
+        "use1-scl1-az1",
+        AwsRegion::UsEast1,
+        "Santiago",
+        -33.4489,
+        -70.6693,
+    ),
+    wavelength_zone(
+        "us-east-1-wl1-atl-wlz-1",
+        "use1-wl1-atl-wlz1",
+        AwsRegion::UsEast1,
+        "Atlanta",
+        33.7490,
+        -84.3880,
+    ),
+    wavelength_zone(
+        "us-east-1-wl1-bos-wlz-1",
+        "use1-wl1-bos-wlz1",
+        AwsRegion::UsEast1,
+        "Boston",
+        42.3601,
+        -71.0589,
+    ),
+    wavelength_zone(
+        "us-east-1-wl1-chi-wlz-1",
+        "use1-wl1-chi-wlz1",
+        AwsRegion::UsEast1,
+        "Chicago",
+        41.8781,
+        -87.6298,
+    ),
+    wavelength_zone(
+        "us-east-1-wl1-clt-wlz-1",
+        "use1-wl1-clt-wlz1",
+        AwsRegion::UsEast1,
+        "Charlotte",
+        35.2271,
+        -80.8431,
+    ),
+    wavelength_zone(
+        "us-east-1-wl1-dfw-wlz-1",
+        "use1-wl1-dfw-wlz1",
+        AwsRegion::UsEast1,
+        "Dallas",
+        32.7767,
+        -96.7970,
+    ),
+    wavelength_zone(
+        "us-east-1-wl1-dtw-wlz-1",
+        "use1-wl1-dtw-wlz1",
+        AwsRegion::UsEast1,
+        "Detroit",
+        42.3314,
+        -83.0458,
+    ),
+    wavelength_zone(
+        "us-east-1-wl1-iah-wlz-1",
+        "use1-wl1-iah-wlz1",
+        AwsRegion::UsEast1,
+        "Houston",
+        29.7604,
+        -95.3698,
+    ),
+    wavelength_zone(
+        "us-east-1-wl1-mia-wlz-1",
+        "use1-wl1-mia-wlz1",
+        AwsRegion::UsEast1,
+        "Miami",
+        25.7617,
+        -80.1918,
+    ),
+    wavelength_zone(
+        "us-east-1-wl1-msp-wlz-1",
+        "use1-wl1-msp-wlz1",
+        AwsRegion::UsEast1,
+        "Minneapolis",
+        44.9778,
+        -93.2650,
+    ),
+    wavelength_zone(
+        "us-east-1-wl1-nyc-wlz-1",
+        "use1-wl1-nyc-wlz1",
+        AwsRegion::UsEast1,
+        "New York",
+        40.7128,
+        -74.0060,
+    ),
+    wavelength_zone(
+        "us-east-1-wl1-tpa-wlz-1",
+        "use1-wl1-tpa-wlz1",
+        AwsRegion::UsEast1,
+        "Tampa",
+        27.9506,
+        -82.4572,
+    ),
+    wavelength_zone(
+        "us-east-1-wl1-was-wlz-1",
+        "use1-wl1-was-wlz1",
+        AwsRegion::UsEast1,
+        "Washington",
+        38.9072,
+        -77.0369,
+    ),
+    local_zone(
+        "us-west-2-den-1a",
+        "usw2-den1-az1",
+        AwsRegion::UsWest2,
+        "Denver",
+        39.7392,
+        -104.9903,
+    ),
+    local_zone(
+        "us-west-2-hnl-1a",
+        "usw2-hnl1-az1",
+        AwsRegion::UsWest2,
+        "Honolulu",
+        21.3069,
+        -157.8583,
+    ),
+    local_zone(
+        "us-west-2-las-1a",
+        "usw2-las1-az1",
+        AwsRegion::UsWest2,
+        "Las Vegas",
+        36.1699,
+        -115.1398,
+    ),
+    local_zone(
+        "us-west-2-lax-1a",
+        "usw2-lax1-az1",
+        AwsRegion::UsWest2,
+        "Los Angeles",
+        34.0522,
+        -118.2437,
+    ),
+    local_zone(
+        "us-west-2-lax-1b",
+        "usw2-lax1-az2",
+        AwsRegion::UsWest2,
+        "Los Angeles",
+        34.0522,
+        -118.2437,
+    ),
+    local_zone(
+        "us-west-2-pdx-1a",
+        "usw2-pdx1-az1",
+        AwsRegion::UsWest2,
+        "Portland",
+        45.5152,
+        -122.6784,
+    ),
+    local_zone(
+        "us-west-2-phx-2a",
+        "usw2-phx2-az1",
+        AwsRegion::UsWest2,
+        "Phoenix",
+        33.4484,
+        -112.0740,
+    ),
+    local_zone(
+        "us-west-2-sea-1a",
+        "usw2-sea1-az1",
+        AwsRegion::UsWest2,
+        "Seattle",
+        47.6062,
+        -122.3321,
+    ),
+    wavelength_zone(
+        "us-west-2-wl1-den-wlz-1",
+        "usw2-wl1-den-wlz1",
+        AwsRegion::UsWest2,
+        "Denver",
+        39.7392,
+        -104.9903,
+    ),
+    wavelength_zone(
+        "us-west-2-wl1-las-wlz-1",
+        "usw2-wl1-las-wlz1",
+        AwsRegion::UsWest2,
+        "Las Vegas",
+        36.1699,
+        -115.1398,
+    ),
+    wavelength_zone(
+        "us-west-2-wl1-lax-wlz-1",
+        "usw2-wl1-lax-wlz1",
+        AwsRegion::UsWest2,
+        "Los Angeles",
+        34.0522,
+        -118.2437,
+    ),
+    wavelength_zone(
+        "us-west-2-wl1-phx-wlz-1",
+        "usw2-wl1-phx-wlz1",
+        AwsRegion::UsWest2,
+        "Phoenix",
+        33.4484,
+        -112.0740,
+    ),
+    wavelength_zone(
+        "us-west-2-wl1-sea-wlz-1",
+        "usw2-wl1-sea-wlz1",
+        AwsRegion::UsWest2,
+        "Seattle",
+        47.6062,
+        -122.3321,
+    ),
+    wavelength_zone(
+        "us-west-2-wl1-sfo-wlz-1",
+        "usw2-wl1-sfo-wlz1",
+        AwsRegion::UsWest2,
+        "San Francisco",
+        37.7749,
+        -122.4194,
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const SANTA_MONICA: (f64, f64) = (34.0195, -118.4912);
+    const LONDON: (f64, f64) = (51.5074, -0.1278);
+
+    #[test]
+    fn test_zone_iter() {
+        assert_eq!(AwsZone::iter().count(), 63);
+        assert!(AwsZone::iter().all(|zone| zone.name().starts_with(zone.region().name())));
+        assert!(AwsZone::iter().all(|zone| zone.name().parse() == Ok(zone)));
+        assert!(AwsZone::iter().all(|zone| zone.id().parse() == Ok(zone)));
+    }
+
+    #[test]
+    fn test_zone_from_str() {
+        struct Test {
+            s: &'static str,
+            zone: Result<&'static str, Error>,
+        }
+
+        let tests = vec![
+            Test {
+                s: "us-west-2-lax-1a",
+                zone: Ok("us-west-2-lax-1a"),
+            },
+            Test {
+                s: "us-west-2-lax-1b",
+                zone: Ok("us-west-2-lax-1b"),
+            },
+            Test {
+                s: "us-west-2-lax-1",
+                zone: Ok("us-west-2-lax-1a"),
+            },
+            Test {
+                s: "USW2-LAX1-AZ2",
+                zone: Ok("us-west-2-lax-1b"),
+            },
+            Test {
+                s: "us-east-1-wl1-bos-wlz-1",
+                zone: Ok("us-east-1-wl1-bos-wlz-1"),
+            },
+            Test {
+                s: "us-west-2",
+                zone: Err(Error::InvalidAwsZone),
+            },
+            Test {
+                s: "us-west-2-lax-1c",
+                zone: Err(Error::InvalidAwsZone),
+            },
+        ];
+
+        for t in tests {
+            let zone = t.s.parse::<AwsZone>().map(|zone| zone.name());
+            assert_eq!(zone, t.zone, "{}", t.s);
+        }
+    }
+
+    #[test]
+    fn test_zone_attributes() {
+        let zone = AwsZone::try_from("us-west-2-lax-1b").unwrap();
+        assert_eq!(zone.id(), "usw2-lax1-az2");
+        assert_eq!(zone.network_border_group(), "us-west-2-lax-1");
+        assert_eq!(zone.region(), AwsRegion::UsWest2);
+        assert_eq!(zone.zone_type(), ZoneType::LocalZone);
+        assert_eq!(zone.city(), "Los Angeles");
+        assert_eq!(zone.to_string(), "us-west-2-lax-1b");
+
+        let zone = AwsZone::try_from("eu-west-2-wl1-man-wlz-1").unwrap();
+        assert_eq!(zone.network_border_group(), "eu-west-2-wl1-man-wlz-1");
+        assert_eq!(zone.zone_type().to_string(), "wavelength-zone");
+    }
+
+    #[test]
+    fn test_find_zone() {
+        assert_eq!(find_zone(SANTA_MONICA).name(), "us-west-2-lax-1a");
+        assert_eq!(find_zone(LONDON).name(), "eu-west-2-wl1-lon-wlz-1");
+
+        let local_zones: Vec<AwsZone> = AwsZone::iter()
+            .filter(|zone| zone.zone_type() == ZoneType::LocalZone)
+            .collect();
+        assert_eq!(find_zone_from_list(LONDON, &local_zones).name(), "eu-central-1-ham-1a");
+    }
+
+    #[test]
+    fn test_find_target_from_list() {
+        struct Test {
+            location: (f64, f64),
+            targets: Vec<&'static str>,
+            target: &'static str,
+        }
+
+        let tests = vec![
+            Test {
+                location: SANTA_MONICA,
+                targets: vec!["us-west-1", "us-west-2", "us-west-2-lax-1"],
+                target: "us-west-2-lax-1a",
+            },
+            Test {
+                location: LONDON,
+                targets: vec!["eu-west-2", "eu-central-1-ham-1a"],
+                target: "eu-west-2",
+            },
+            Test {
+                location: (53.4808, -2.2426),
+                targets: vec!["eu-west-2", "eu-west-1", "euw2-wl1-man-wlz1"],
+                target: "eu-west-2-wl1-man-wlz-1",
+            },
+        ];
+
+        for t in tests {
+            let targets: Vec<RoutingTarget> = t.targets.iter().map(|s| s.parse().unwrap()).collect();
+            let target = find_target_from_list(t.location, &targets);
+            assert_eq!(target.name(), t.target);
+        }
+    }
+
+    #[test]
+    fn test_routing_target() {
+        let target = RoutingTarget::from(AwsZone::try_from("us-east-1-nyc-1").unwrap());
+        assert_eq!(target.region(), AwsRegion::UsEast1);
+        assert_eq!(target.to_string(), "us-east-1-nyc-1a");
+        assert_eq!("eu-west-1".parse(), Ok(RoutingTarget::Region(AwsRegion::EuWest1)));
+        assert_eq!("eu-west-9".parse::<RoutingTarget>(), Err(Error::InvalidAwsZone));
+    }
+}
@@ -1,33 +1,44 @@
 // Usage:   cargo run <latitude> <longitude>
 // Example: cargo run 40.714728 -73.998672
+//          cargo run "40°42'51\"N 74°00'21\"W"
+//          cargo run geo:40.714728,-73.998672
+
+use aws_region_nearby::Coordinates;
 
 fn main() {
     let args: Vec<String> = std::env::args().skip(1).collect();
-    let latitude = args[0].parse::<f64>().unwrap();
-    let longitude = args[1].parse::<f64>().unwrap();
-
-    aws_sdk_example(latitude, longitude);
-    rusoto_example(latitude, longitude);
+    let coordinates: Coordinates = match args.join(" ").parse() {
+        Ok(coordinates) => coordinates,
+        Err(e) => {
+            eprintln!("{e}: expected <latitude> <longitude>");
+            std::process::exit(1);
+        }
+    };
+
+    aws_sdk_example(coordinates);
+    rusoto_example(coordinates);
 }
 
-fn aws_sdk_example(latitude: f64, longitude: f64) {
+fn aws_sdk_example(coordinates: Coordinates) {
     use aws_region_nearby::find_region;
     use aws_types::region::Region;
 
-    let region = Region::from_static(find_region(latitude, longitude).name());
+    let region = Region::from(find_region(coordinates));
 
     println!("AWS SDK region = {region:?}");
 }
 
-fn rusoto_example(latitude: f64, longitude: f64) {
-    use aws_region_nearby::{find_region_from_list, AwsRegion};
+fn rusoto_example(coordinates: Coordinates) {
+    use aws_region_nearby::{aws_region, find_region_from_list};
 
-    let replica_regions: Vec<AwsRegion> = ["us-west-1", "us-east-1", "eu-central-1", "ap-northeast-1"]
-        .iter()
-        .map(|r| r.parse().unwrap())
-        .collect();
+    let replica_regions = [
+        aws_region!("us-west-1"),
+        aws_region!("us-east-1"),
+        aws_region!("eu-central-1"),
+        aws_region!("ap-northeast-1"),
+    ];
 
-    let region: rusoto_core::Region = find_region_from_list(latitude, longitude, &replica_regions)
+    let region: rusoto_core::Region = find_region_from_list(coordinates, &replica_regions)
         .name()
         .parse()
         .unwrap();
@@ -0,0 +1,36 @@
+//! A minimal WebAssembly module that only uses the allocation-free core of
+//! aws-region-nearby, so that CI can track its code size.
+
+#![cfg_attr(target_arch = "wasm32", no_std)]
+
+use aws_region_nearby::{find_region, AwsRegion};
+
+/// Finds the nearest AWS region to the given location and returns a pointer
+/// to its name, writing the length of the name to `len`.
+///
+/// # Safety
+///
+/// `len` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn nearest_region(latitude: f64, longitude: f64, len: *mut usize) -> *const u8 {
+    let name = find_region((latitude, longitude)).name();
+    *len = name.len();
+    name.as_ptr()
+}
+
+/// Returns whether the UTF-8 string at `ptr` is a valid AWS region name.
+///
+/// # Safety
+///
+/// `ptr` must be valid for reads of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn is_region(ptr: *const u8, len: usize) -> bool {
+    let bytes = core::slice::from_raw_parts(ptr, len);
+    core::str::from_utf8(bytes).is_ok_and(|name| name.parse::<AwsRegion>().is_ok())
+}
+
+#[cfg(target_arch = "wasm32")]
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo<'_>) -> ! {
+    core::arch::wasm32::unreachable()
+}